@@ -1,6 +1,7 @@
 library;
 
 mod owner_integration;
+mod roles;
 
 // ANCHOR: import
 use {admin::*, ownership::*};
@@ -45,3 +46,14 @@ fn check_if_admin(admin: Identity) {
     assert(status);
 }
 // ANCHOR_END: check_admin
+
+// ANCHOR: list_admins
+#[storage(read)]
+fn list_admins() -> Vec<Identity> {
+    let total = admins_len();
+    assert(admin_at(total) == None);
+
+    // Returns at most the first 10 admins.
+    admins(0, 10)
+}
+// ANCHOR_END: list_admins
@@ -0,0 +1,59 @@
+library;
+
+// ANCHOR: roles_import
+use admin::roles::*;
+// ANCHOR_END: roles_import
+
+// ANCHOR: role_constants
+// Pre-computed hash digest of sha256("minter")
+const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+// Pre-computed hash digest of sha256("minter_admin")
+const MINTER_ADMIN_ROLE: b256 = 0xe0c9003b9fca7c35422aa060416252c37826278d9e8a16a0f64cdc4820100799;
+// ANCHOR_END: role_constants
+
+// ANCHOR: grant_role
+#[storage(read, write)]
+fn grant_minter(minter: Identity) {
+    // Can only be called by the contract's owner or a holder of the minter's admin role.
+    grant_role(MINTER_ROLE, minter);
+}
+// ANCHOR_END: grant_role
+
+// ANCHOR: revoke_role
+#[storage(read, write)]
+fn revoke_minter(minter: Identity) {
+    // Can only be called by the contract's owner or a holder of the minter's admin role.
+    revoke_role(MINTER_ROLE, minter);
+}
+// ANCHOR_END: revoke_role
+
+// ANCHOR: renounce_role
+#[storage(read, write)]
+fn give_up_minter() {
+    renounce_role(MINTER_ROLE);
+}
+// ANCHOR_END: renounce_role
+
+// ANCHOR: set_role_admin
+#[storage(read, write)]
+fn set_minter_admin() {
+    // Can only be called by contract's owner.
+    set_role_admin(MINTER_ROLE, MINTER_ADMIN_ROLE);
+}
+// ANCHOR_END: set_role_admin
+
+// ANCHOR: only_role
+#[storage(read)]
+fn only_minter_may_call() {
+    only_role(MINTER_ROLE);
+    // Only a minter may reach this line.
+}
+// ANCHOR_END: only_role
+
+// ANCHOR: has_role
+#[storage(read)]
+fn check_if_minter(minter: Identity) {
+    let status = has_role(MINTER_ROLE, minter);
+    assert(status);
+}
+// ANCHOR_END: has_role
@@ -0,0 +1,85 @@
+contract;
+
+use std::hash::Hash;
+
+// ANCHOR: import
+use asset::allowance::*;
+// ANCHOR_END: import
+
+// ANCHOR: allowance_storage
+storage {
+    balances: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+    allowances: StorageMap<(Identity, Identity, AssetId), u64> = StorageMap {},
+}
+// ANCHOR_END: allowance_storage
+
+// ANCHOR: ledger
+abi Ledger {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit(recipient: Identity) -> u64;
+
+    #[storage(read, write)]
+    fn withdraw(recipient: Identity, asset: AssetId, amount: u64);
+
+    #[storage(read)]
+    fn ledger_balance(owner: Identity, asset: AssetId) -> u64;
+}
+
+impl Ledger for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit(recipient: Identity) -> u64 {
+        _deposit(storage.balances, recipient)
+    }
+
+    #[storage(read, write)]
+    fn withdraw(recipient: Identity, asset: AssetId, amount: u64) {
+        _withdraw(storage.balances, recipient, asset, amount);
+    }
+
+    #[storage(read)]
+    fn ledger_balance(owner: Identity, asset: AssetId) -> u64 {
+        _ledger_balance(storage.balances, owner, asset)
+    }
+}
+// ANCHOR_END: ledger
+
+// ANCHOR: allowance
+abi Allowance {
+    #[storage(write)]
+    fn approve(spender: Identity, asset: AssetId, amount: u64);
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity, asset: AssetId) -> u64;
+
+    #[storage(read, write)]
+    fn transfer_from(owner: Identity, recipient: Identity, asset: AssetId, amount: u64);
+}
+
+impl Allowance for Contract {
+    #[storage(write)]
+    fn approve(spender: Identity, asset: AssetId, amount: u64) {
+        _approve(storage.allowances, spender, asset, amount);
+    }
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity, asset: AssetId) -> u64 {
+        _allowance(storage.allowances, owner, spender, asset)
+    }
+
+    #[storage(read, write)]
+    fn transfer_from(owner: Identity, recipient: Identity, asset: AssetId, amount: u64) {
+        _transfer_from(
+            storage
+                .balances,
+            storage
+                .allowances,
+            owner,
+            recipient,
+            asset,
+            amount,
+        );
+    }
+}
+// ANCHOR_END: allowance
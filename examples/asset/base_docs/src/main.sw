@@ -24,11 +24,11 @@ abi SRC20 {
 
 // ANCHOR: set_attributes
 abi SetAssetAttributes {
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_name(asset: AssetId, name: String);
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_symbol(asset: AssetId, symbol: String);
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_decimals(asset: AssetId, decimals: u8);
 }
 // ANCHOR_END: set_attributes
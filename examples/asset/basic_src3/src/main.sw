@@ -9,6 +9,7 @@ use src3::SRC3;
 storage {
     total_assets: u64 = 0,
     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+    max_supply: StorageMap<AssetId, u64> = StorageMap {},
 }
 
 // Implement the SRC-3 Standard for this contract
@@ -21,6 +22,8 @@ impl SRC3 for Contract {
                 .total_assets,
             storage
                 .total_supply,
+            storage
+                .max_supply,
             recipient,
             sub_id
                 .unwrap_or(b256::zero()),
@@ -0,0 +1,45 @@
+contract;
+
+use std::hash::*;
+
+// ANCHOR: guarded_src3
+use guarded_asset::{_guarded_burn, _guarded_mint, AccessPolicy};
+use src3::SRC3;
+
+// Pre-computed hash digest of sha256("minter")
+const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+
+storage {
+    total_assets: u64 = 0,
+    total_supply: StorageMap<AssetId, u64> = StorageMap {},
+    max_supply: StorageMap<AssetId, u64> = StorageMap {},
+}
+
+// Implement the SRC-3 Standard for this contract
+impl SRC3 for Contract {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, sub_id: Option<SubId>, amount: u64) {
+        // Only holders of the minter role may mint, and only while the contract is unpaused.
+        let _ = _guarded_mint(
+            AccessPolicy::Role(MINTER_ROLE),
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            storage
+                .max_supply,
+            recipient,
+            sub_id
+                .unwrap_or(b256::zero()),
+            amount,
+        );
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64) {
+        // Only the contract owner may burn, and only while the contract is unpaused.
+        _guarded_burn(AccessPolicy::Owner, storage.total_supply, sub_id, amount);
+    }
+}
+// ANCHOR_END: guarded_src3
@@ -3,7 +3,27 @@ contract;
 use std::{bytes::Bytes, string::String};
 
 // ANCHOR: import
-use asset::metadata::{_metadata, _set_metadata, SetAssetMetadata, StorageMetadata};
+use asset::metadata::{
+    _default_metadata,
+    _freeze_metadata,
+    _freeze_metadata_key,
+    _is_default_metadata,
+    _is_metadata_frozen,
+    _metadata,
+    _metadata_batch,
+    _metadata_key_at,
+    _metadata_keys_len,
+    _metadata_schema,
+    _remove_metadata,
+    _remove_metadata_schema,
+    _set_default_metadata,
+    _set_metadata,
+    _set_metadata_batch,
+    _set_metadata_schema,
+    MetadataSchema,
+    SetAssetMetadata,
+    StorageMetadata,
+};
 use src7::*;
 // ANCHOR_END: import
 
@@ -40,6 +60,168 @@ impl SetAssetMetadata for Contract {
 }
 // ANCHOR_END: src7_set_metadata
 
+// ANCHOR: metadata_batch
+abi MetadataBatch {
+    #[storage(read, write)]
+    fn set_metadata_batch(entries: Vec<(AssetId, String, Metadata)>);
+
+    #[storage(read)]
+    fn metadata_batch(queries: Vec<(AssetId, String)>) -> Vec<Option<Metadata>>;
+}
+
+impl MetadataBatch for Contract {
+    #[storage(read, write)]
+    fn set_metadata_batch(entries: Vec<(AssetId, String, Metadata)>) {
+        // add your authentication logic here
+        // eg. only_owner()
+        _set_metadata_batch(storage.metadata, entries);
+    }
+
+    #[storage(read)]
+    fn metadata_batch(queries: Vec<(AssetId, String)>) -> Vec<Option<Metadata>> {
+        _metadata_batch(storage.metadata, queries)
+    }
+}
+// ANCHOR_END: metadata_batch
+
+// ANCHOR: metadata_schema
+abi MetadataSchemas {
+    #[storage(write)]
+    fn set_metadata_schema(key: String, schema: MetadataSchema);
+
+    #[storage(write)]
+    fn remove_metadata_schema(key: String);
+
+    #[storage(read)]
+    fn metadata_schema(key: String) -> Option<MetadataSchema>;
+}
+
+impl MetadataSchemas for Contract {
+    #[storage(write)]
+    fn set_metadata_schema(key: String, schema: MetadataSchema) {
+        // add your authentication logic here
+        // eg. only_owner()
+        _set_metadata_schema(storage.metadata, key, schema);
+    }
+
+    #[storage(write)]
+    fn remove_metadata_schema(key: String) {
+        // add your authentication logic here
+        // eg. only_owner()
+        _remove_metadata_schema(storage.metadata, key);
+    }
+
+    #[storage(read)]
+    fn metadata_schema(key: String) -> Option<MetadataSchema> {
+        _metadata_schema(storage.metadata, key)
+    }
+}
+// ANCHOR_END: metadata_schema
+
+// ANCHOR: default_metadata
+abi DefaultMetadata {
+    #[storage(read, write)]
+    fn set_default_metadata(key: String, metadata: Metadata);
+
+    #[storage(read)]
+    fn default_metadata(key: String) -> Option<Metadata>;
+
+    #[storage(read)]
+    fn is_default_metadata(asset: AssetId, key: String) -> bool;
+}
+
+impl DefaultMetadata for Contract {
+    #[storage(read, write)]
+    fn set_default_metadata(key: String, metadata: Metadata) {
+        // add your authentication logic here
+        // eg. only_owner()
+        _set_default_metadata(storage.metadata, key, metadata);
+    }
+
+    #[storage(read)]
+    fn default_metadata(key: String) -> Option<Metadata> {
+        _default_metadata(storage.metadata, key)
+    }
+
+    #[storage(read)]
+    fn is_default_metadata(asset: AssetId, key: String) -> bool {
+        _is_default_metadata(storage.metadata, asset, key)
+    }
+}
+// ANCHOR_END: default_metadata
+
+// ANCHOR: remove_metadata
+abi RemoveMetadata {
+    #[storage(read, write)]
+    fn remove_metadata(asset: AssetId, key: String) -> bool;
+}
+
+impl RemoveMetadata for Contract {
+    #[storage(read, write)]
+    fn remove_metadata(asset: AssetId, key: String) -> bool {
+        // add your authentication logic here
+        // eg. only_owner()
+        _remove_metadata(storage.metadata, asset, key)
+    }
+}
+// ANCHOR_END: remove_metadata
+
+// ANCHOR: metadata_keys
+abi MetadataKeys {
+    #[storage(read)]
+    fn metadata_keys_len(asset: AssetId) -> u64;
+
+    #[storage(read)]
+    fn metadata_key_at(asset: AssetId, index: u64) -> Option<String>;
+}
+
+impl MetadataKeys for Contract {
+    #[storage(read)]
+    fn metadata_keys_len(asset: AssetId) -> u64 {
+        _metadata_keys_len(storage.metadata, asset)
+    }
+
+    #[storage(read)]
+    fn metadata_key_at(asset: AssetId, index: u64) -> Option<String> {
+        _metadata_key_at(storage.metadata, asset, index)
+    }
+}
+// ANCHOR_END: metadata_keys
+
+// ANCHOR: freeze_metadata
+abi FreezeMetadata {
+    #[storage(read, write)]
+    fn freeze_metadata(asset: AssetId);
+
+    #[storage(read, write)]
+    fn freeze_metadata_key(asset: AssetId, key: String);
+
+    #[storage(read)]
+    fn is_metadata_frozen(asset: AssetId) -> bool;
+}
+
+impl FreezeMetadata for Contract {
+    #[storage(read, write)]
+    fn freeze_metadata(asset: AssetId) {
+        // add your authentication logic here
+        // eg. only_owner()
+        _freeze_metadata(storage.metadata, asset);
+    }
+
+    #[storage(read, write)]
+    fn freeze_metadata_key(asset: AssetId, key: String) {
+        // add your authentication logic here
+        // eg. only_owner()
+        _freeze_metadata_key(storage.metadata, asset, key);
+    }
+
+    #[storage(read)]
+    fn is_metadata_frozen(asset: AssetId) -> bool {
+        _is_metadata_frozen(storage.metadata, asset)
+    }
+}
+// ANCHOR_END: freeze_metadata
+
 #[storage(read)]
 fn get_metadata(asset: AssetId, key: String) {
     // ANCHOR: get_metadata
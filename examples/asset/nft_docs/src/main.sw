@@ -0,0 +1,93 @@
+contract;
+
+use std::{hash::Hash, string::String};
+
+// ANCHOR: import
+use asset::{metadata::*, nft::*};
+use src3::SRC3;
+use src7::{Metadata, SRC7};
+// ANCHOR_END: import
+
+// ANCHOR: nft_storage
+storage {
+    total_assets: u64 = 0,
+    total_supply: StorageMap<AssetId, u64> = StorageMap {},
+    max_supply: StorageMap<AssetId, u64> = StorageMap {},
+    metadata: StorageMetadata = StorageMetadata {},
+}
+// ANCHOR_END: nft_storage
+
+// ANCHOR: nft_src3
+impl SRC3 for Contract {
+    #[storage(read, write)]
+    fn mint(recipient: Identity, sub_id: Option<SubId>, amount: u64) {
+        // Each non-fungible asset has a supply of exactly one.
+        require(amount == 1, "Amount must be one");
+        // Ensure this function is restricted, for example with the Ownership Library.
+        let _ = _mint_nft(
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            storage
+                .max_supply,
+            recipient,
+            sub_id
+                .unwrap_or(b256::zero()),
+        );
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn burn(sub_id: SubId, amount: u64) {
+        require(amount == 1, "Amount must be one");
+        _burn_nft(storage.total_supply, sub_id);
+    }
+}
+// ANCHOR_END: nft_src3
+
+// ANCHOR: nft_src7
+impl SRC7 for Contract {
+    #[storage(read)]
+    fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+        _metadata(storage.metadata, asset, key)
+    }
+}
+// ANCHOR_END: nft_src7
+
+// ANCHOR: token_uri
+abi TokenUri {
+    #[storage(read, write)]
+    fn set_token_uri(asset: AssetId, uri: String);
+
+    #[storage(read)]
+    fn token_uri(asset: AssetId) -> Option<String>;
+}
+
+impl TokenUri for Contract {
+    #[storage(read, write)]
+    fn set_token_uri(asset: AssetId, uri: String) {
+        // Ensure this function is restricted, for example with the Ownership Library.
+        _set_token_uri(storage.metadata, asset, uri);
+    }
+
+    #[storage(read)]
+    fn token_uri(asset: AssetId) -> Option<String> {
+        _token_uri(storage.metadata, asset)
+    }
+}
+// ANCHOR_END: token_uri
+
+// ANCHOR: is_nft_minted
+abi NftInfo {
+    #[storage(read)]
+    fn is_nft_minted(asset: AssetId) -> bool;
+}
+
+impl NftInfo for Contract {
+    #[storage(read)]
+    fn is_nft_minted(asset: AssetId) -> bool {
+        _is_nft_minted(storage.total_supply, asset)
+    }
+}
+// ANCHOR_END: is_nft_minted
@@ -1,29 +1,30 @@
 contract;
 
 // ANCHOR: setting_src20_attributes
-use asset::base::*;
+use asset::{base::*, metadata::StorageMetadata};
 use std::{hash::Hash, storage::storage_string::*, string::String};
 
 storage {
     name: StorageMap<AssetId, StorageString> = StorageMap {},
     symbol: StorageMap<AssetId, StorageString> = StorageMap {},
     decimals: StorageMap<AssetId, u8> = StorageMap {},
+    metadata: StorageMetadata = StorageMetadata {},
 }
 
 impl SetAssetAttributes for Contract {
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_name(asset: AssetId, name: String) {
-        _set_name(storage.name, asset, name);
+        _set_name(storage.name, storage.metadata, asset, name);
     }
 
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_symbol(asset: AssetId, symbol: String) {
-        _set_symbol(storage.symbol, asset, symbol);
+        _set_symbol(storage.symbol, storage.metadata, asset, symbol);
     }
 
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_decimals(asset: AssetId, decimals: u8) {
-        _set_decimals(storage.decimals, asset, decimals);
+        _set_decimals(storage.decimals, storage.metadata, asset, decimals);
     }
 }
 // ANCHOR_END: setting_src20_attributes
@@ -21,5 +21,141 @@ abi SRC3 {
 storage {
     total_assets: u64 = 0,
     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+    max_supply: StorageMap<AssetId, u64> = StorageMap {},
 }
 // ANCHOR_END: src3_storage
+
+// ANCHOR: max_supply
+abi MaxSupply {
+    #[storage(read)]
+    fn max_supply(asset: AssetId) -> Option<u64>;
+
+    #[storage(write)]
+    fn set_max_supply(asset: AssetId, max_supply: u64);
+}
+
+impl MaxSupply for Contract {
+    #[storage(read)]
+    fn max_supply(asset: AssetId) -> Option<u64> {
+        _max_supply(storage.max_supply, asset)
+    }
+
+    #[storage(write)]
+    fn set_max_supply(asset: AssetId, max_supply: u64) {
+        // Ensure this function is restricted, for example with the Ownership Library.
+        _set_max_supply(storage.max_supply, asset, max_supply);
+    }
+}
+// ANCHOR_END: max_supply
+
+// ANCHOR: burn_forwarded
+abi BurnForwarded {
+    #[payable]
+    #[storage(read, write)]
+    fn burn_forwarded(sub_id: SubId) -> u64;
+}
+
+impl BurnForwarded for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn burn_forwarded(sub_id: SubId) -> u64 {
+        // Burns exactly the coins forwarded with this call.
+        _burn_forwarded(storage.total_supply, sub_id)
+    }
+}
+// ANCHOR_END: burn_forwarded
+
+// ANCHOR: batch
+abi Batch {
+    #[storage(read, write)]
+    fn mint_batch(recipients: Vec<(Identity, SubId, u64)>);
+
+    #[storage(read, write)]
+    fn burn_batch(burns: Vec<(SubId, u64)>);
+}
+
+impl Batch for Contract {
+    #[storage(read, write)]
+    fn mint_batch(recipients: Vec<(Identity, SubId, u64)>) {
+        // Logs one `TotalSupplyEvent` per distinct asset minted.
+        _mint_batch(
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            storage
+                .max_supply,
+            recipients,
+        );
+    }
+
+    #[storage(read, write)]
+    fn burn_batch(burns: Vec<(SubId, u64)>) {
+        // Logs one `TotalSupplyEvent` per distinct asset burned.
+        _burn_batch(storage.total_supply, burns);
+    }
+}
+// ANCHOR_END: batch
+
+// ANCHOR: enumeration
+abi Enumeration {
+    #[storage(read)]
+    fn asset_at(index: u64) -> Option<AssetId>;
+
+    #[storage(read)]
+    fn sub_id_at(index: u64) -> Option<SubId>;
+}
+
+impl Enumeration for Contract {
+    #[storage(read)]
+    fn asset_at(index: u64) -> Option<AssetId> {
+        _asset_at(storage.total_assets, index)
+    }
+
+    #[storage(read)]
+    fn sub_id_at(index: u64) -> Option<SubId> {
+        _sub_id_at(storage.total_assets, index)
+    }
+}
+// ANCHOR_END: enumeration
+
+// ANCHOR: total_supply_at
+abi SupplyCheckpoints {
+    #[storage(read)]
+    fn total_supply_at(asset: AssetId, height: u32) -> Option<u64>;
+}
+
+impl SupplyCheckpoints for Contract {
+    #[storage(read)]
+    fn total_supply_at(asset: AssetId, height: u32) -> Option<u64> {
+        _total_supply_at(storage.total_supply, asset, height)
+    }
+}
+// ANCHOR_END: total_supply_at
+
+// ANCHOR: sub_id
+abi SerialMint {
+    #[storage(read, write)]
+    fn mint_serial(recipient: Identity, serial: u64) -> AssetId;
+}
+
+impl SerialMint for Contract {
+    #[storage(read, write)]
+    fn mint_serial(recipient: Identity, serial: u64) -> AssetId {
+        // add your authentication logic here
+        // eg. only_owner()
+        let sub_id = asset::sub_id::sub_id_from_serial(serial);
+        _mint(
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            storage
+                .max_supply,
+            recipient,
+            sub_id,
+            1,
+        )
+    }
+}
+// ANCHOR_END: sub_id
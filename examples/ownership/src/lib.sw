@@ -47,6 +47,39 @@ fn transfer_contract_ownership(new_owner: Identity) {
 }
 // ANCHOR_END: transfer_ownership
 
+// ANCHOR: propose_ownership_transfer
+#[storage(read, write)]
+fn propose_contract_owner(new_owner: Identity) {
+    // The caller must be the current owner.
+    propose_ownership_transfer(new_owner);
+    // The current owner remains the owner until the new owner accepts.
+}
+// ANCHOR_END: propose_ownership_transfer
+
+// ANCHOR: accept_ownership
+#[storage(read, write)]
+fn accept_contract_ownership() {
+    // The caller must be the pending owner.
+    accept_ownership();
+    // The caller is now the owner.
+}
+// ANCHOR_END: accept_ownership
+
+// ANCHOR: cancel_ownership_transfer
+#[storage(read, write)]
+fn cancel_contract_owner_proposal() {
+    // The caller must be the current owner.
+    cancel_ownership_transfer();
+}
+// ANCHOR_END: cancel_ownership_transfer
+
+// ANCHOR: pending_owner
+#[storage(read)]
+fn get_pending_owner() {
+    let pending_owner: Option<Identity> = _pending_owner();
+}
+// ANCHOR_END: pending_owner
+
 // ANCHOR: renouncing_ownership
 #[storage(read, write)]
 fn renounce_contract_owner() {
@@ -55,3 +88,51 @@ fn renounce_contract_owner() {
     // Now no one owns the contract.
 }
 // ANCHOR_END: renouncing_ownership
+
+// ANCHOR: timelock_import
+use ownership::timelock::*;
+// ANCHOR_END: timelock_import
+
+// ANCHOR: initialize_ownership_delay
+// 1 day in seconds
+const OWNERSHIP_TRANSFER_DELAY: u64 = 86400;
+
+#[storage(read, write)]
+fn set_minimum_ownership_delay() {
+    // The caller must be the current owner.
+    initialize_ownership_delay(OWNERSHIP_TRANSFER_DELAY);
+    // The minimum delay can never be changed.
+}
+// ANCHOR_END: initialize_ownership_delay
+
+// ANCHOR: schedule_ownership_transfer
+#[storage(read, write)]
+fn schedule_contract_owner(new_owner: Identity) {
+    // The caller must be the current owner and the delay must be at least the minimum delay.
+    schedule_ownership_transfer(new_owner, OWNERSHIP_TRANSFER_DELAY);
+    // The transfer may be executed in 1 day.
+}
+// ANCHOR_END: schedule_ownership_transfer
+
+// ANCHOR: execute_ownership_transfer
+#[storage(read, write)]
+fn execute_contract_owner() {
+    // The caller must be the current owner and the delay must have elapsed.
+    execute_ownership_transfer();
+}
+// ANCHOR_END: execute_ownership_transfer
+
+// ANCHOR: cancel_scheduled_ownership_transfer
+#[storage(read, write)]
+fn cancel_contract_owner_schedule() {
+    // The caller must be the current owner.
+    cancel_scheduled_ownership_transfer();
+}
+// ANCHOR_END: cancel_scheduled_ownership_transfer
+
+// ANCHOR: scheduled_ownership_transfer
+#[storage(read)]
+fn get_scheduled_owner() {
+    let scheduled: Option<(Identity, u64)> = _scheduled_ownership_transfer();
+}
+// ANCHOR_END: scheduled_ownership_transfer
@@ -62,6 +62,32 @@ fn proxy_target() -> Option<ContractId> {
 }
 // ANCHOR_END: proxy_target
 
+// ANCHOR: proxy_target_history
+#[storage(read)]
+fn proxy_target_history() {
+    let len: u64 = _proxy_target_history_len();
+    if len > 0 {
+        let (latest_target, block_height) = _proxy_target_history(len - 1).unwrap();
+    }
+}
+// ANCHOR_END: proxy_target_history
+
+// ANCHOR: rollback_proxy_target
+#[storage(read, write)]
+fn rollback_to_previous_target() {
+    // The caller must be the current proxy owner.
+    rollback_proxy_target();
+}
+// ANCHOR_END: rollback_proxy_target
+
+// ANCHOR: initialize_proxy_owner
+#[storage(read, write)]
+fn initialize_proxy(new_proxy_owner: Identity) {
+    // Reverts if the proxy owner has already been set.
+    initialize_proxy_owner(new_proxy_owner);
+}
+// ANCHOR_END: initialize_proxy_owner
+
 // ANCHOR: set_proxy_owner
 #[storage(write)]
 fn set_proxy_owner(new_proxy_owner: State) {
@@ -76,6 +102,37 @@ fn proxy_owner() -> State {
 }
 // ANCHOR_END: proxy_owner
 
+// ANCHOR: renounce_proxy_owner
+#[storage(read, write)]
+fn give_up_proxy_owner() {
+    // The caller must be the current proxy owner.
+    renounce_proxy_owner();
+}
+// ANCHOR_END: renounce_proxy_owner
+
+// ANCHOR: propose_proxy_owner
+#[storage(read, write)]
+fn propose_new_proxy_owner(new_proxy_owner: Identity) {
+    // The caller must be the current proxy owner.
+    propose_proxy_owner(new_proxy_owner);
+}
+// ANCHOR_END: propose_proxy_owner
+
+// ANCHOR: accept_proxy_owner
+#[storage(read, write)]
+fn accept_new_proxy_owner() {
+    // The caller must be the pending proxy owner.
+    accept_proxy_owner();
+}
+// ANCHOR_END: accept_proxy_owner
+
+// ANCHOR: pending_proxy_owner
+#[storage(read)]
+fn pending_proxy_owner() -> Option<Identity> {
+    _pending_proxy_owner()
+}
+// ANCHOR_END: pending_proxy_owner
+
 // ANCHOR: only_proxy_owner
 #[storage(read)]
 fn only_proxy_owner_may_call() {
@@ -83,3 +140,105 @@ fn only_proxy_owner_may_call() {
     // Only the proxy's owner may reach this line.
 }
 // ANCHOR_END: only_proxy_owner
+
+// ANCHOR: timelock_import
+use std::block::timestamp;
+use upgradability::timelock::*;
+// ANCHOR_END: timelock_import
+
+// ANCHOR: initialize_proxy_delay
+// 1 day in seconds
+const PROXY_OWNER_DELAY: u64 = 86400;
+
+#[storage(read, write)]
+fn set_minimum_proxy_delay() {
+    // The caller must be the current proxy owner.
+    initialize_proxy_delay(PROXY_OWNER_DELAY);
+    // The minimum delay can never be changed.
+}
+// ANCHOR_END: initialize_proxy_delay
+
+// ANCHOR: schedule_proxy_owner
+#[storage(read, write)]
+fn schedule_new_proxy_owner(new_proxy_owner: State) {
+    // The caller must be the current proxy owner and the delay must be at least the minimum delay.
+    schedule_proxy_owner(new_proxy_owner, PROXY_OWNER_DELAY);
+}
+// ANCHOR_END: schedule_proxy_owner
+
+// ANCHOR: execute_proxy_owner
+#[storage(read, write)]
+fn execute_new_proxy_owner() {
+    // The caller must be the current proxy owner and the delay must have elapsed.
+    execute_proxy_owner();
+}
+// ANCHOR_END: execute_proxy_owner
+
+// ANCHOR: cancel_scheduled_proxy_owner
+#[storage(read, write)]
+fn cancel_new_proxy_owner() {
+    // The caller must be the current proxy owner.
+    cancel_scheduled_proxy_owner();
+}
+// ANCHOR_END: cancel_scheduled_proxy_owner
+
+// ANCHOR: schedule_proxy_target
+#[storage(read, write)]
+fn schedule_new_proxy_target(new_target: ContractId) {
+    // The caller must be the current proxy owner.
+    // The upgrade may be executed in 1 day.
+    schedule_proxy_target(new_target, timestamp() + 86400);
+}
+// ANCHOR_END: schedule_proxy_target
+
+// ANCHOR: execute_proxy_target
+#[storage(read, write)]
+fn execute_new_proxy_target() {
+    // The caller must be the current proxy owner and the eta must have been reached.
+    execute_proxy_target();
+}
+// ANCHOR_END: execute_proxy_target
+
+// ANCHOR: cancel_proxy_target
+#[storage(read, write)]
+fn cancel_new_proxy_target() {
+    // The caller must be the current proxy owner.
+    cancel_proxy_target();
+}
+// ANCHOR_END: cancel_proxy_target
+
+// ANCHOR: pending_proxy_target
+#[storage(read)]
+fn get_pending_proxy_target() -> Option<(ContractId, u64)> {
+    _pending_proxy_target()
+}
+// ANCHOR_END: pending_proxy_target
+
+// ANCHOR: verification_import
+use upgradability::verification::*;
+// ANCHOR_END: verification_import
+
+// ANCHOR: approve_implementation_root
+#[storage(read, write)]
+fn approve_implementation(root: b256) {
+    // The caller must be the current proxy owner.
+    approve_implementation_root(root);
+}
+// ANCHOR_END: approve_implementation_root
+
+// ANCHOR: revoke_implementation_root
+#[storage(read, write)]
+fn revoke_implementation(root: b256) {
+    // The caller must be the current proxy owner.
+    revoke_implementation_root(root);
+}
+// ANCHOR_END: revoke_implementation_root
+
+// ANCHOR: set_proxy_target_verified
+#[storage(read, write)]
+fn set_verified_proxy_target(new_target: ContractId, expected_root: b256) {
+    // The caller must be the current proxy owner, `expected_root` must be approved
+    // and `new_target` must have been deployed with the bytecode of `expected_root`.
+    _set_proxy_target_verified(new_target, expected_root);
+}
+// ANCHOR_END: set_proxy_target_verified
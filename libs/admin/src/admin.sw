@@ -1,14 +1,34 @@
 library;
 
 pub mod errors;
+pub mod events;
+pub mod roles;
 
 use ::errors::AdminError;
+use ::events::{AdminAdded, AdminRevoked};
 use ownership::{_owner, only_owner};
 use src5::State;
-use std::{auth::msg_sender, hash::{Hash, sha256}, storage::storage_api::clear};
+use std::{
+    auth::msg_sender,
+    hash::{
+        Hash,
+        sha256,
+    },
+    storage::{
+        storage_api::clear,
+        storage_vec::*,
+    },
+};
+
+// Pre-computed hash digest of sha256("admins")
+const ADMINS = 0xfa956b808c8f8e3b59be14d7d584761e041a8359d58ba7e1829f12605d76203a;
 
 // Sets a new administrator.
 ///
+/// # Additional Information
+///
+/// No event is logged when `new_admin` is already an administrator.
+///
 /// # Arguments
 ///
 /// * `new_admin`: [Identity] - The `Identity` which is to receive administrator status.
@@ -19,8 +39,8 @@ use std::{auth::msg_sender, hash::{Hash, sha256}, storage::storage_api::clear};
 ///
 /// # Number of Storage Accesses
 ///
-/// * Reads: `1`
-/// * Writes: `1`
+/// * Reads: `4`
+/// * Writes: `4`
 ///
 /// # Examples
 ///
@@ -38,11 +58,39 @@ pub fn add_admin(new_admin: Identity) {
 
     let key_digest = sha256(("admin", new_admin.bits()));
     let admin_key = StorageKey::<Identity>::new(key_digest, 0, key_digest);
+    let index_key = admin_index_key(new_admin);
+    let admin_list = admins_key();
+    let owner = msg_sender().unwrap();
+    match index_key.try_read() {
+        Some(index) => {
+            let current_admin = admin_list.get(index).unwrap().read();
+            if current_admin == new_admin {
+                return;
+            }
+
+            // An administrator with the same bits shares the slot and loses its status.
+            admin_list.set(index, new_admin);
+            log(AdminRevoked {
+                old_admin: current_admin,
+                owner,
+            });
+        },
+        None => {
+            index_key.write(admin_list.len());
+            admin_list.push(new_admin);
+        },
+    }
     admin_key.write(new_admin);
+
+    log(AdminAdded { new_admin, owner });
 }
 
 /// Removes an administrator.
 ///
+/// # Additional Information
+///
+/// No event is logged when `old_admin` is not an administrator.
+///
 /// # Arguments
 ///
 /// * `old_admin`: [Identity] - The `Identity` which the administrator status is to be removed.
@@ -53,8 +101,8 @@ pub fn add_admin(new_admin: Identity) {
 ///
 /// # Number of Storage Accesses
 ///
-/// * Reads: `1`
-/// * Writes: `1`
+/// * Reads: `8`
+/// * Writes: `5`
 ///
 /// # Examples
 ///
@@ -70,9 +118,35 @@ pub fn add_admin(new_admin: Identity) {
 pub fn revoke_admin(old_admin: Identity) {
     only_owner();
 
+    if !is_admin(old_admin) {
+        return;
+    }
+
     let key_digest = sha256(("admin", old_admin.bits()));
     let admin_key = StorageKey::<Identity>::new(key_digest, 0, key_digest);
+    let index_key = admin_index_key(old_admin);
+    match index_key.try_read() {
+        Some(index) => {
+            let admin_list = admins_key();
+
+            // The last administrator is moved into the removed administrator's position.
+            let _ = admin_list.swap_remove(index);
+            if index < admin_list.len() {
+                let moved_admin = admin_list.get(index).unwrap().read();
+                admin_index_key(moved_admin).write(index);
+            }
+
+            let _ = index_key.clear();
+        },
+        // Administrators added before enumeration existed have no index and are not in the list.
+        None => (),
+    }
     let _ = admin_key.clear();
+
+    log(AdminRevoked {
+        old_admin,
+        owner: msg_sender().unwrap(),
+    });
 }
 
 /// Returns whether `admin` is an administrator.
@@ -169,3 +243,123 @@ pub fn only_owner_or_admin() {
         AdminError::NotAdmin,
     );
 }
+
+/// Returns the number of administrators.
+///
+/// # Returns
+///
+/// * [u64] - The number of `Identity`s with administrator status.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::{add_admin, admins_len};
+///
+/// fn foo(new_admin: Identity) {
+///     assert(admins_len() == 0);
+///     add_admin(new_admin);
+///     assert(admins_len() == 1);
+/// }
+/// ```
+#[storage(read)]
+pub fn admins_len() -> u64 {
+    admins_key().len()
+}
+
+/// Returns the administrator at `index`.
+///
+/// # Additional Information
+///
+/// The order of administrators is not stable. When an administrator is revoked, the last administrator takes its position.
+///
+/// # Arguments
+///
+/// * `index`: [u64] - The index of the administrator.
+///
+/// # Returns
+///
+/// * [Option<Identity>] - The administrator at `index`, or `None` if `index` is out of bounds.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::{add_admin, admin_at};
+///
+/// fn foo(new_admin: Identity) {
+///     add_admin(new_admin);
+///     assert(admin_at(0) == Some(new_admin));
+///     assert(admin_at(1) == None);
+/// }
+/// ```
+#[storage(read)]
+pub fn admin_at(index: u64) -> Option<Identity> {
+    match admins_key().get(index) {
+        Some(admin_key) => Some(admin_key.read()),
+        None => None,
+    }
+}
+
+/// Returns up to `count` administrators starting at `start`.
+///
+/// # Additional Information
+///
+/// Fewer than `count` administrators are returned when the end of the set is reached.
+///
+/// # Arguments
+///
+/// * `start`: [u64] - The index of the first administrator to return.
+/// * `count`: [u64] - The maximum number of administrators to return.
+///
+/// # Returns
+///
+/// * [Vec<Identity>] - The administrators from `start` onwards.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1 + count`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::{add_admin, admins};
+///
+/// fn foo(admin_1: Identity, admin_2: Identity) {
+///     add_admin(admin_1);
+///     add_admin(admin_2);
+///
+///     let page = admins(1, 10);
+///     assert(page.len() == 1);
+///     assert(page.get(0).unwrap() == admin_2);
+/// }
+/// ```
+#[storage(read)]
+pub fn admins(start: u64, count: u64) -> Vec<Identity> {
+    let admin_list = admins_key();
+    let len = admin_list.len();
+    let mut result = Vec::new();
+
+    let mut index = start;
+    while index < len && index - start < count {
+        result.push(admin_list.get(index).unwrap().read());
+        index += 1;
+    }
+
+    result
+}
+
+fn admins_key() -> StorageKey<StorageVec<Identity>> {
+    StorageKey::new(ADMINS, 0, ADMINS)
+}
+
+fn admin_index_key(admin: Identity) -> StorageKey<u64> {
+    let key_digest = sha256(("admin_index", admin.bits()));
+    StorageKey::new(key_digest, 0, key_digest)
+}
@@ -5,3 +5,11 @@ pub enum AdminError {
     /// Emitted when the caller is not an admin.
     NotAdmin: (),
 }
+
+/// Error log for when access to a role is denied.
+pub enum RoleError {
+    /// Emitted when the caller does not have the required role.
+    MissingRole: (),
+    /// Emitted when the caller is neither the contract owner nor holds the admin role of a role.
+    NotRoleAdmin: (),
+}
@@ -0,0 +1,47 @@
+library;
+
+/// Logged when a user is given administrator status.
+pub struct AdminAdded {
+    /// The user which is now an administrator.
+    pub new_admin: Identity,
+    /// The owner which added the administrator.
+    pub owner: Identity,
+}
+
+/// Logged when a user's administrator status is removed.
+pub struct AdminRevoked {
+    /// The user which is no longer an administrator.
+    pub old_admin: Identity,
+    /// The owner which revoked the administrator.
+    pub owner: Identity,
+}
+
+/// Logged when a role is granted to a user.
+pub struct RoleGranted {
+    /// The role which has been granted.
+    pub role: b256,
+    /// The user which has been granted the role.
+    pub account: Identity,
+    /// The user which granted the role.
+    pub sender: Identity,
+}
+
+/// Logged when a role is revoked from a user.
+pub struct RoleRevoked {
+    /// The role which has been revoked.
+    pub role: b256,
+    /// The user which has had the role revoked.
+    pub account: Identity,
+    /// The user which revoked the role.
+    pub sender: Identity,
+}
+
+/// Logged when the admin role of a role is changed.
+pub struct RoleAdminChanged {
+    /// The role which has had its admin role changed.
+    pub role: b256,
+    /// The admin role before the change.
+    pub previous_admin_role: b256,
+    /// The admin role after the change.
+    pub new_admin_role: b256,
+}
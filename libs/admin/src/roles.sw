@@ -0,0 +1,336 @@
+library;
+
+use ::errors::RoleError;
+use ::events::{RoleAdminChanged, RoleGranted, RoleRevoked};
+use ownership::{_owner, only_owner};
+use src5::State;
+use std::{auth::msg_sender, hash::{Hash, sha256}};
+
+/// The role which administers all roles that have not been assigned a different admin role.
+///
+/// # Additional Information
+///
+/// The contract owner may always grant and revoke any role, including this one.
+pub const DEFAULT_ADMIN_ROLE: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000;
+
+/// Grants `role` to `account`.
+///
+/// # Additional Information
+///
+/// If `account` already has `role`, no storage is written and no event is logged.
+///
+/// # Arguments
+///
+/// * `role`: [b256] - The role which is to be granted.
+/// * `account`: [Identity] - The `Identity` which is to receive the role.
+///
+/// # Reverts
+///
+/// * When the caller is not the contract owner and does not have the admin role of `role`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `4`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::roles::{grant_role, has_role};
+///
+/// // Pre-computed hash digest of sha256("minter")
+/// const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+///
+/// fn foo(minter: Identity) {
+///     grant_role(MINTER_ROLE, minter);
+///     assert(has_role(MINTER_ROLE, minter));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn grant_role(role: b256, account: Identity) {
+    only_owner_or_role_admin(role);
+
+    if has_role(role, account) {
+        return;
+    }
+
+    let role_key = role_key(role, account);
+    role_key.write(account);
+
+    log(RoleGranted {
+        role,
+        account,
+        sender: msg_sender().unwrap(),
+    });
+}
+
+/// Revokes `role` from `account`.
+///
+/// # Additional Information
+///
+/// If `account` does not have `role`, no storage is cleared and no event is logged.
+///
+/// # Arguments
+///
+/// * `role`: [b256] - The role which is to be revoked.
+/// * `account`: [Identity] - The `Identity` which the role is to be revoked from.
+///
+/// # Reverts
+///
+/// * When the caller is not the contract owner and does not have the admin role of `role`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `4`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::roles::{has_role, revoke_role};
+///
+/// // Pre-computed hash digest of sha256("minter")
+/// const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+///
+/// fn foo(minter: Identity) {
+///     revoke_role(MINTER_ROLE, minter);
+///     assert(!has_role(MINTER_ROLE, minter));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn revoke_role(role: b256, account: Identity) {
+    only_owner_or_role_admin(role);
+
+    if !has_role(role, account) {
+        return;
+    }
+
+    let role_key = role_key(role, account);
+    let _ = role_key.clear();
+
+    log(RoleRevoked {
+        role,
+        account,
+        sender: msg_sender().unwrap(),
+    });
+}
+
+/// Revokes `role` from the caller.
+///
+/// # Additional Information
+///
+/// If the caller does not have `role`, no storage is cleared and no event is logged.
+///
+/// # Arguments
+///
+/// * `role`: [b256] - The role which the caller is to give up.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::roles::{has_role, renounce_role};
+///
+/// // Pre-computed hash digest of sha256("minter")
+/// const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+///
+/// fn foo() {
+///     renounce_role(MINTER_ROLE);
+///     assert(!has_role(MINTER_ROLE, msg_sender().unwrap()));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn renounce_role(role: b256) {
+    let sender = msg_sender().unwrap();
+
+    if !has_role(role, sender) {
+        return;
+    }
+
+    let role_key = role_key(role, sender);
+    let _ = role_key.clear();
+
+    log(RoleRevoked {
+        role,
+        account: sender,
+        sender,
+    });
+}
+
+/// Returns whether `account` has `role`.
+///
+/// # Arguments
+///
+/// * `role`: [b256] - The role to check.
+/// * `account`: [Identity] - The `Identity` of which to check the role.
+///
+/// # Returns
+///
+/// * [bool] - `true` if `account` has `role`, otherwise `false`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::roles::has_role;
+///
+/// // Pre-computed hash digest of sha256("minter")
+/// const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+///
+/// fn foo(minter: Identity) {
+///     assert(has_role(MINTER_ROLE, minter));
+/// }
+/// ```
+#[storage(read)]
+pub fn has_role(role: b256, account: Identity) -> bool {
+    let role_key = role_key(role, account);
+    match role_key.try_read() {
+        Some(identity) => {
+            account == identity
+        },
+        None => {
+            false
+        },
+    }
+}
+
+/// Ensures that the sender has `role`.
+///
+/// # Additional Information
+///
+/// NOTE: The contract owner does not implicitly hold any role. If the owner calls this function without having `role`, it will revert.
+///
+/// # Arguments
+///
+/// * `role`: [b256] - The role the sender is required to have.
+///
+/// # Reverts
+///
+/// * When the caller does not have `role`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::roles::only_role;
+///
+/// // Pre-computed hash digest of sha256("minter")
+/// const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+///
+/// fn foo() {
+///     only_role(MINTER_ROLE);
+///     // Only reachable by a minter
+/// }
+/// ```
+#[storage(read)]
+pub fn only_role(role: b256) {
+    require(has_role(role, msg_sender().unwrap()), RoleError::MissingRole);
+}
+
+/// Returns the admin role of `role`.
+///
+/// # Additional Information
+///
+/// Holders of the admin role may grant and revoke `role`. Unless changed with `set_role_admin()`, this is the `DEFAULT_ADMIN_ROLE`.
+///
+/// # Arguments
+///
+/// * `role`: [b256] - The role of which to return the admin role.
+///
+/// # Returns
+///
+/// * [b256] - The admin role of `role`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::roles::{_role_admin, DEFAULT_ADMIN_ROLE};
+///
+/// // Pre-computed hash digest of sha256("minter")
+/// const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+///
+/// fn foo() {
+///     assert(_role_admin(MINTER_ROLE) == DEFAULT_ADMIN_ROLE);
+/// }
+/// ```
+#[storage(read)]
+pub fn _role_admin(role: b256) -> b256 {
+    let key_digest = sha256(("role_admin", role));
+    let role_admin_key = StorageKey::<b256>::new(key_digest, 0, key_digest);
+    role_admin_key.try_read().unwrap_or(DEFAULT_ADMIN_ROLE)
+}
+
+/// Sets the admin role of `role`.
+///
+/// # Arguments
+///
+/// * `role`: [b256] - The role of which the admin role is to be changed.
+/// * `new_admin_role`: [b256] - The role which will administer `role`.
+///
+/// # Reverts
+///
+/// * When the caller is not the contract owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use admin::roles::{_role_admin, set_role_admin};
+///
+/// // Pre-computed hash digest of sha256("minter")
+/// const MINTER_ROLE: b256 = 0xbe9677d2ea649220f63b2ccf6275a49a0a64e9f59dd9961d69a01a8d525788f8;
+/// // Pre-computed hash digest of sha256("minter_admin")
+/// const MINTER_ADMIN_ROLE: b256 = 0xe0c9003b9fca7c35422aa060416252c37826278d9e8a16a0f64cdc4820100799;
+///
+/// fn foo() {
+///     set_role_admin(MINTER_ROLE, MINTER_ADMIN_ROLE);
+///     assert(_role_admin(MINTER_ROLE) == MINTER_ADMIN_ROLE);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn set_role_admin(role: b256, new_admin_role: b256) {
+    only_owner();
+
+    let previous_admin_role = _role_admin(role);
+    let key_digest = sha256(("role_admin", role));
+    let role_admin_key = StorageKey::<b256>::new(key_digest, 0, key_digest);
+    role_admin_key.write(new_admin_role);
+
+    log(RoleAdminChanged {
+        role,
+        previous_admin_role,
+        new_admin_role,
+    });
+}
+
+#[storage(read)]
+fn only_owner_or_role_admin(role: b256) {
+    let sender = msg_sender().unwrap();
+    require(
+        _owner() == State::Initialized(sender) || has_role(_role_admin(role), sender),
+        RoleError::NotRoleAdmin,
+    );
+}
+
+fn role_key(role: b256, account: Identity) -> StorageKey<Identity> {
+    let key_digest = sha256(("role", role, account));
+    StorageKey::<Identity>::new(key_digest, 0, key_digest)
+}
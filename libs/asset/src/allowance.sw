@@ -0,0 +1,338 @@
+library;
+
+use ::errors::AllowanceError;
+use ::events::{Approval, LedgerDeposit, LedgerTransfer, LedgerWithdrawal};
+use std::{
+    asset::transfer,
+    call_frames::msg_asset_id,
+    context::msg_amount,
+    hash::Hash,
+};
+
+/// Credits the coins forwarded with the call to a user in the internal ledger.
+///
+/// # Additional Information
+///
+/// The coins are held by the calling contract and may be moved by the recipient with `_withdraw()` or by an approved spender with `_transfer_from()`.
+/// The calling contract function must be `#[payable]`.
+///
+/// # Arguments
+///
+/// * `balances_key`: [StorageKey<StorageMap<(Identity, AssetId), u64>>] - The location in storage which the `StorageMap` that stores the internal ledger balances is stored.
+/// * `recipient`: [Identity] - The user to be credited the forwarded coins.
+///
+/// # Returns
+///
+/// * [u64] - The quantity of coins deposited.
+///
+/// # Reverts
+///
+/// * When no coins are forwarded.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::allowance::{_deposit, _ledger_balance};
+/// use std::{call_frames::msg_asset_id, context::msg_amount};
+///
+/// storage {
+///     balances: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+/// }
+///
+/// #[payable]
+/// #[storage(read, write)]
+/// fn foo(recipient: Identity) {
+///     let deposited = _deposit(storage.balances, recipient);
+///     assert(_ledger_balance(storage.balances, recipient, msg_asset_id()) >= deposited);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _deposit(
+    balances_key: StorageKey<StorageMap<(Identity, AssetId), u64>>,
+    recipient: Identity,
+) -> u64 {
+    let amount = msg_amount();
+    require(amount > 0, AllowanceError::ZeroAmount);
+
+    let asset = msg_asset_id();
+    let balance = _ledger_balance(balances_key, recipient, asset);
+    balances_key.insert((recipient, asset), balance + amount);
+
+    log(LedgerDeposit {
+        recipient,
+        asset,
+        amount,
+        sender: msg_sender().unwrap(),
+    });
+
+    amount
+}
+
+/// Debits coins from the caller in the internal ledger and transfers them out of the contract.
+///
+/// # Arguments
+///
+/// * `balances_key`: [StorageKey<StorageMap<(Identity, AssetId), u64>>] - The location in storage which the `StorageMap` that stores the internal ledger balances is stored.
+/// * `recipient`: [Identity] - The user to which the coins are transferred.
+/// * `asset`: [AssetId] - The asset to withdraw.
+/// * `amount`: [u64] - The quantity of coins to withdraw.
+///
+/// # Reverts
+///
+/// * When `amount` is zero.
+/// * When the caller does not hold `amount` coins of `asset` in the internal ledger.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::allowance::{_ledger_balance, _withdraw};
+///
+/// storage {
+///     balances: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+/// }
+///
+/// #[storage(read, write)]
+/// fn foo(asset: AssetId) {
+///     let sender = msg_sender().unwrap();
+///     _withdraw(storage.balances, sender, asset, 100);
+///     assert(_ledger_balance(storage.balances, sender, asset) == 0);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _withdraw(
+    balances_key: StorageKey<StorageMap<(Identity, AssetId), u64>>,
+    recipient: Identity,
+    asset: AssetId,
+    amount: u64,
+) {
+    require(amount > 0, AllowanceError::ZeroAmount);
+
+    let owner = msg_sender().unwrap();
+    let balance = _ledger_balance(balances_key, owner, asset);
+    require(balance >= amount, AllowanceError::InsufficientBalance);
+    balances_key.insert((owner, asset), balance - amount);
+
+    transfer(recipient, asset, amount);
+    log(LedgerWithdrawal {
+        owner,
+        recipient,
+        asset,
+        amount,
+    });
+}
+
+/// Returns the quantity of coins a user holds in the internal ledger.
+///
+/// # Arguments
+///
+/// * `balances_key`: [StorageKey<StorageMap<(Identity, AssetId), u64>>] - The location in storage which the `StorageMap` that stores the internal ledger balances is stored.
+/// * `owner`: [Identity] - The user of which to return the balance.
+/// * `asset`: [AssetId] - The asset of which to return the balance.
+///
+/// # Returns
+///
+/// * [u64] - The quantity of coins of `asset` held by `owner`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::allowance::_ledger_balance;
+///
+/// storage {
+///     balances: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+/// }
+///
+/// fn foo(owner: Identity, asset: AssetId) {
+///     let balance = _ledger_balance(storage.balances, owner, asset);
+///     assert(balance == 0);
+/// }
+/// ```
+#[storage(read)]
+pub fn _ledger_balance(
+    balances_key: StorageKey<StorageMap<(Identity, AssetId), u64>>,
+    owner: Identity,
+    asset: AssetId,
+) -> u64 {
+    balances_key.get((owner, asset)).try_read().unwrap_or(0)
+}
+
+/// Sets the quantity of coins of an asset which a spender may move on behalf of the caller.
+///
+/// # Additional Information
+///
+/// Any previous allowance is replaced. Approving an amount of zero revokes the allowance.
+///
+/// # Arguments
+///
+/// * `allowances_key`: [StorageKey<StorageMap<(Identity, Identity, AssetId), u64>>] - The location in storage which the `StorageMap` that stores the allowances is stored.
+/// * `spender`: [Identity] - The user which may move the coins.
+/// * `asset`: [AssetId] - The asset which may be moved.
+/// * `amount`: [u64] - The quantity of coins which may be moved.
+///
+/// # Number of Storage Accesses
+///
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::allowance::{_allowance, _approve};
+///
+/// storage {
+///     allowances: StorageMap<(Identity, Identity, AssetId), u64> = StorageMap {},
+/// }
+///
+/// #[storage(read, write)]
+/// fn foo(spender: Identity, asset: AssetId) {
+///     _approve(storage.allowances, spender, asset, 100);
+///     assert(_allowance(storage.allowances, msg_sender().unwrap(), spender, asset) == 100);
+/// }
+/// ```
+#[storage(write)]
+pub fn _approve(
+    allowances_key: StorageKey<StorageMap<(Identity, Identity, AssetId), u64>>,
+    spender: Identity,
+    asset: AssetId,
+    amount: u64,
+) {
+    let owner = msg_sender().unwrap();
+    allowances_key.insert((owner, spender, asset), amount);
+
+    log(Approval {
+        owner,
+        spender,
+        asset,
+        amount,
+    });
+}
+
+/// Returns the quantity of coins of an asset which a spender may move on behalf of an owner.
+///
+/// # Arguments
+///
+/// * `allowances_key`: [StorageKey<StorageMap<(Identity, Identity, AssetId), u64>>] - The location in storage which the `StorageMap` that stores the allowances is stored.
+/// * `owner`: [Identity] - The user which holds the coins.
+/// * `spender`: [Identity] - The user which may move the coins.
+/// * `asset`: [AssetId] - The asset which may be moved.
+///
+/// # Returns
+///
+/// * [u64] - The remaining allowance of `spender`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::allowance::_allowance;
+///
+/// storage {
+///     allowances: StorageMap<(Identity, Identity, AssetId), u64> = StorageMap {},
+/// }
+///
+/// fn foo(owner: Identity, spender: Identity, asset: AssetId) {
+///     assert(_allowance(storage.allowances, owner, spender, asset) == 0);
+/// }
+/// ```
+#[storage(read)]
+pub fn _allowance(
+    allowances_key: StorageKey<StorageMap<(Identity, Identity, AssetId), u64>>,
+    owner: Identity,
+    spender: Identity,
+    asset: AssetId,
+) -> u64 {
+    allowances_key.get((owner, spender, asset)).try_read().unwrap_or(0)
+}
+
+/// Moves coins held in the internal ledger from an owner to a recipient on behalf of the owner.
+///
+/// # Additional Information
+///
+/// The caller is the spender and its allowance is reduced by `amount`. The coins remain held by the contract and are credited to `recipient` in the internal ledger.
+///
+/// # Arguments
+///
+/// * `balances_key`: [StorageKey<StorageMap<(Identity, AssetId), u64>>] - The location in storage which the `StorageMap` that stores the internal ledger balances is stored.
+/// * `allowances_key`: [StorageKey<StorageMap<(Identity, Identity, AssetId), u64>>] - The location in storage which the `StorageMap` that stores the allowances is stored.
+/// * `owner`: [Identity] - The user from which the coins are moved.
+/// * `recipient`: [Identity] - The user to which the coins are moved.
+/// * `asset`: [AssetId] - The asset to move.
+/// * `amount`: [u64] - The quantity of coins to move.
+///
+/// # Reverts
+///
+/// * When `amount` is zero.
+/// * When the allowance of the caller is less than `amount`.
+/// * When `owner` does not hold `amount` coins of `asset` in the internal ledger.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `3`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::allowance::{_ledger_balance, _transfer_from};
+///
+/// storage {
+///     balances: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+///     allowances: StorageMap<(Identity, Identity, AssetId), u64> = StorageMap {},
+/// }
+///
+/// #[storage(read, write)]
+/// fn foo(owner: Identity, asset: AssetId) {
+///     let sender = msg_sender().unwrap();
+///     _transfer_from(storage.balances, storage.allowances, owner, sender, asset, 100);
+///     assert(_ledger_balance(storage.balances, sender, asset) >= 100);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _transfer_from(
+    balances_key: StorageKey<StorageMap<(Identity, AssetId), u64>>,
+    allowances_key: StorageKey<StorageMap<(Identity, Identity, AssetId), u64>>,
+    owner: Identity,
+    recipient: Identity,
+    asset: AssetId,
+    amount: u64,
+) {
+    require(amount > 0, AllowanceError::ZeroAmount);
+
+    let spender = msg_sender().unwrap();
+    let allowance = _allowance(allowances_key, owner, spender, asset);
+    require(allowance >= amount, AllowanceError::InsufficientAllowance);
+
+    let owner_balance = _ledger_balance(balances_key, owner, asset);
+    require(owner_balance >= amount, AllowanceError::InsufficientBalance);
+
+    allowances_key.insert((owner, spender, asset), allowance - amount);
+    balances_key.insert((owner, asset), owner_balance - amount);
+
+    // Read the recipient's balance after debiting the owner in case they are the same user.
+    let recipient_balance = _ledger_balance(balances_key, recipient, asset);
+    balances_key.insert((recipient, asset), recipient_balance + amount);
+
+    log(LedgerTransfer {
+        from: owner,
+        to: recipient,
+        asset,
+        amount,
+        sender: spender,
+    });
+}
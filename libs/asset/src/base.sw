@@ -3,6 +3,7 @@ library;
 use std::{hash::{Hash, sha256}, storage::storage_string::*, string::String};
 use src20::{SetDecimalsEvent, SetNameEvent, SetSymbolEvent};
 use ::errors::SetMetadataError;
+use ::metadata::{_is_metadata_frozen, StorageMetadata};
 
 /// Returns the total number of individual assets for a contract.
 ///
@@ -191,7 +192,7 @@ pub fn _decimals(
     decimals_key.get(asset).try_read()
 }
 
-/// Unconditionally sets the name of an asset.
+/// Sets the name of an asset.
 ///
 /// # Additional Information
 ///
@@ -200,39 +201,45 @@ pub fn _decimals(
 /// # Arguments
 ///
 /// * `name_key`: [StorageKey<StorageMap<AssetId, StorageKey<StorageString>>>] - The location in storage which the `StorageMap` that stores the names of assets is stored.
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata which records whether the metadata of `asset` has been frozen.
 /// * `asset`: [AssetId] - The asset of which to set the name.
 /// * `name`: [String] - The name of the asset.
 ///
 /// # Reverts
 ///
+/// * When the metadata of `asset` has been frozen.
 /// * When passing an empty string.
 ///
 /// # Number of Storage Accesses
 ///
+/// * Reads: `1`
 /// * Writes: `2`
 ///
 /// # Examples
 ///
 /// ```sway
-/// use asset::base::{_set_name, _name};
+/// use asset::{base::{_set_name, _name}, metadata::StorageMetadata};
 /// use std::string::String;
 ///
 /// storage {
 ///     name: StorageMap<AssetId, StorageString> = StorageMap {},
+///     metadata: StorageMetadata = StorageMetadata {},
 /// }
 ///
 /// fn foo(asset: AssetId) {
 ///     let name = String::from_ascii_str("Ether");
-///     _set_name(storage.name, asset, name);
+///     _set_name(storage.name, storage.metadata, asset, name);
 ///     assert(_name(storage.name, asset).unwrap() == name);
 /// }
 /// ```
-#[storage(write)]
+#[storage(read, write)]
 pub fn _set_name(
     name_key: StorageKey<StorageMap<AssetId, StorageString>>,
+    metadata_key: StorageKey<StorageMetadata>,
     asset: AssetId,
     name: String,
 ) {
+    require(!_is_metadata_frozen(metadata_key, asset), SetMetadataError::Frozen);
     require(!name.is_empty(), SetMetadataError::EmptyString);
 
     name_key.insert(asset, StorageString {});
@@ -245,7 +252,7 @@ pub fn _set_name(
     });
 }
 
-/// Unconditionally sets the symbol of an asset.
+/// Sets the symbol of an asset.
 ///
 /// # Additional Information
 ///
@@ -254,39 +261,45 @@ pub fn _set_name(
 /// # Arguments
 ///
 /// * `symbol_key`: [StorageKey<StorageMap<AssetId, StorageKey<StorageString>>>] - The location in storage which the `StorageMap` that stores the symbols of assets is stored.
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata which records whether the metadata of `asset` has been frozen.
 /// * `asset`: [AssetId] - The asset of which to set the symbol.
 /// * `symbol`: [String] - The symbol of the asset.
 ///
 /// # Reverts
 ///
+/// * When the metadata of `asset` has been frozen.
 /// * When passing an empty string.
 ///
 /// # Number of Storage Accesses
 ///
+/// * Reads: `1`
 /// * Writes: `2`
 ///
 /// # Examples
 ///
 /// ```sway
-/// use asset::base::{_set_symbol, _symbol};
+/// use asset::{base::{_set_symbol, _symbol}, metadata::StorageMetadata};
 /// use std::string::String;
 ///
 /// storage {
 ///     symbol: StorageMap<AssetId, StorageString> = StorageMap {},
+///     metadata: StorageMetadata = StorageMetadata {},
 /// }
 ///
 /// fn foo(asset: AssetId) {
 ///     let symbol = String::from_ascii_str("ETH");
-///     _set_symbol(storage.symbol, asset, symbol);
+///     _set_symbol(storage.symbol, storage.metadata, asset, symbol);
 ///     assert(_symbol(storage.symbol, asset).unwrap() == symbol);
 /// }
 /// ```
-#[storage(write)]
+#[storage(read, write)]
 pub fn _set_symbol(
     symbol_key: StorageKey<StorageMap<AssetId, StorageString>>,
+    metadata_key: StorageKey<StorageMetadata>,
     asset: AssetId,
     symbol: String,
 ) {
+    require(!_is_metadata_frozen(metadata_key, asset), SetMetadataError::Frozen);
     require(!symbol.is_empty(), SetMetadataError::EmptyString);
 
     symbol_key.insert(asset, StorageString {});
@@ -299,7 +312,7 @@ pub fn _set_symbol(
     });
 }
 
-/// Unconditionally sets the decimals of an asset.
+/// Sets the decimals of an asset.
 ///
 /// # Additional Information
 ///
@@ -308,34 +321,43 @@ pub fn _set_symbol(
 /// # Arguments
 ///
 /// * `decimals_key`: [StorageKey<StorageMap<AssetId, u8>>] - The location in storage which the `StorageMap` that stores the decimals of assets is stored.
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata which records whether the metadata of `asset` has been frozen.
 /// * `asset`: [AssetId] - The asset of which to set the decimals.
 /// * `decimal`: [u8] - The decimals of the asset.
 ///
+/// # Reverts
+///
+/// * When the metadata of `asset` has been frozen.
+///
 /// # Number of Storage Accesses
 ///
+/// * Reads: `1`
 /// * Writes: `1`
 ///
 /// # Examples
 ///
 /// ```sway
-/// use asset::base::{_set_decimals, _decimals};
+/// use asset::{base::{_set_decimals, _decimals}, metadata::StorageMetadata};
 ///
 /// storage {
 ///     decimals: StorageMap<AssetId, u8> = StorageMap {},
+///     metadata: StorageMetadata = StorageMetadata {},
 /// }
 ///
 /// fn foo(asset: AssetId) {
 ///     let decimals = 8u8;
-///     _set_decimals(storage.decimals, asset, decimals);
+///     _set_decimals(storage.decimals, storage.metadata, asset, decimals);
 ///     assert(_decimals(storage.decimals, asset).unwrap() == decimals);
 /// }
 /// ```
-#[storage(write)]
+#[storage(read, write)]
 pub fn _set_decimals(
     decimals_key: StorageKey<StorageMap<AssetId, u8>>,
+    metadata_key: StorageKey<StorageMetadata>,
     asset: AssetId,
     decimals: u8,
 ) {
+    require(!_is_metadata_frozen(metadata_key, asset), SetMetadataError::Frozen);
     decimals_key.insert(asset, decimals);
 
     log(SetDecimalsEvent {
@@ -366,7 +388,7 @@ abi SetAssetAttributes {
     ///     assert(contract_abi.name(asset) == name);
     /// }
     /// ```
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_name(asset: AssetId, name: String);
     /// Stores the symbol for a specific asset.
     ///
@@ -388,7 +410,7 @@ abi SetAssetAttributes {
     ///     assert(contract_abi.symbol(asset) == symbol);
     /// }
     /// ```
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_symbol(asset: AssetId, symbol: String);
     /// Stores the decimals for a specific asset.
     ///
@@ -409,6 +431,6 @@ abi SetAssetAttributes {
     ///     assert(contract_abi.decimals(asset).unwrap() == decimals);
     /// }
     /// ```
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_decimals(asset: AssetId, decimals: u8);
 }
@@ -6,12 +6,16 @@ pub enum BurnError {
     NotEnoughCoins: (),
     /// Emitted when attempting to burn zero coins.
     ZeroAmount: (),
+    /// Emitted when the forwarded coins are not of the asset being burned.
+    IncorrectAsset: (),
 }
 
 /// Error log for when something goes wrong when minting assets.
 pub enum MintError {
     /// Emitted when attempting to mint zero coins.
     ZeroAmount: (),
+    /// Emitted when minting would exceed the maximum supply of the asset.
+    MaxSupplyExceeded: (),
 }
 
 /// Error log for when something goes wrong when setting metadata.
@@ -20,4 +24,26 @@ pub enum SetMetadataError {
     EmptyString: (),
     /// Emitted when the metadata is empty bytes.
     EmptyBytes: (),
+    /// Emitted when the metadata has been frozen.
+    Frozen: (),
+    /// Emitted when the metadata is not of the type registered in the schema of its key.
+    TypeMismatch: (),
+    /// Emitted when the metadata is longer than the maximum length registered in the schema of its key.
+    MaxLengthExceeded: (),
+}
+
+/// Error log for when something goes wrong when minting non-fungible assets.
+pub enum NftError {
+    /// Emitted when the non-fungible asset has already been minted.
+    AlreadyMinted: (),
+}
+
+/// Error log for when something goes wrong when moving assets held in the internal ledger.
+pub enum AllowanceError {
+    /// Emitted when attempting to move zero coins.
+    ZeroAmount: (),
+    /// Emitted when the owner does not hold enough coins in the internal ledger.
+    InsufficientBalance: (),
+    /// Emitted when the spender has not been approved to move enough coins.
+    InsufficientAllowance: (),
 }
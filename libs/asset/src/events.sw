@@ -0,0 +1,74 @@
+library;
+
+use src7::Metadata;
+use std::string::String;
+
+/// Logged when the metadata of an asset is frozen.
+pub struct MetadataFrozen {
+    /// The asset of which the metadata has been frozen.
+    pub asset: AssetId,
+    /// The metadata key which has been frozen, or `None` if all metadata and attributes of the asset have been frozen.
+    pub key: Option<String>,
+    /// The user which froze the metadata.
+    pub sender: Identity,
+}
+
+/// Logged when the default metadata of all assets is set.
+pub struct SetDefaultMetadata {
+    /// The key of the default metadata.
+    pub key: String,
+    /// The default metadata which has been set.
+    pub metadata: Metadata,
+    /// The user which set the default metadata.
+    pub sender: Identity,
+}
+
+/// Logged when coins are deposited into the internal ledger.
+pub struct LedgerDeposit {
+    /// The user which has been credited the coins.
+    pub recipient: Identity,
+    /// The asset which has been deposited.
+    pub asset: AssetId,
+    /// The quantity of coins deposited.
+    pub amount: u64,
+    /// The user which deposited the coins.
+    pub sender: Identity,
+}
+
+/// Logged when coins are withdrawn from the internal ledger.
+pub struct LedgerWithdrawal {
+    /// The user which has been debited the coins.
+    pub owner: Identity,
+    /// The user to which the coins have been transferred.
+    pub recipient: Identity,
+    /// The asset which has been withdrawn.
+    pub asset: AssetId,
+    /// The quantity of coins withdrawn.
+    pub amount: u64,
+}
+
+/// Logged when coins are moved between users of the internal ledger.
+pub struct LedgerTransfer {
+    /// The user which has been debited the coins.
+    pub from: Identity,
+    /// The user which has been credited the coins.
+    pub to: Identity,
+    /// The asset which has been moved.
+    pub asset: AssetId,
+    /// The quantity of coins moved.
+    pub amount: u64,
+    /// The user which moved the coins.
+    pub sender: Identity,
+}
+
+/// Logged when an owner sets the allowance of a spender.
+pub struct Approval {
+    /// The user which holds the coins.
+    pub owner: Identity,
+    /// The user which may move the coins.
+    pub spender: Identity,
+    /// The asset which may be moved.
+    pub asset: AssetId,
+    /// The quantity of coins which may be moved.
+    pub amount: u64,
+}
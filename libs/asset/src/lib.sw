@@ -1,6 +1,10 @@
 library;
 
 pub mod errors;
+pub mod events;
 pub mod base;
 pub mod metadata;
 pub mod supply;
+pub mod nft;
+pub mod allowance;
+pub mod sub_id;
@@ -10,6 +10,7 @@ use std::{
     },
     storage::{
         storage_api::{
+            clear,
             read,
             write,
         },
@@ -19,6 +20,27 @@ use std::{
     string::String,
 };
 use ::errors::SetMetadataError;
+use ::events::{MetadataFrozen, SetDefaultMetadata};
+
+/// The type of metadata which may be stored under a key.
+pub enum MetadataType {
+    /// Metadata of the `Metadata::Int` variant.
+    Int: (),
+    /// Metadata of the `Metadata::B256` variant.
+    B256: (),
+    /// Metadata of the `Metadata::String` variant.
+    String: (),
+    /// Metadata of the `Metadata::Bytes` variant.
+    Bytes: (),
+}
+
+/// The schema which all metadata stored under a key must conform to.
+pub struct MetadataSchema {
+    /// The type of metadata which may be stored under the key.
+    pub metadata_type: MetadataType,
+    /// The maximum length in bytes of `String` and `Bytes` metadata, or `None` for no limit.
+    pub max_length: Option<u64>,
+}
 
 /// A persistent storage type to store the SRC-7; Metadata Standard type.
 ///
@@ -38,12 +60,15 @@ impl StorageKey<StorageMetadata> {
     ///
     /// # Reverts
     ///
+    /// * When the metadata of `asset` or `key` has been frozen.
     /// * When the metadata is an empty string.
     /// * When the metadata is an empty bytes.
+    /// * When the metadata does not conform to the schema registered for `key`.
     ///
     /// # Number of Storage Accesses
     ///
-    /// * Writes: `2`
+    /// * Reads: `5`
+    /// * Writes: `5`
     ///
     /// # Example
     ///
@@ -62,142 +87,958 @@ impl StorageKey<StorageMetadata> {
     /// ```
     #[storage(read, write)]
     pub fn insert(self, asset: AssetId, key: String, metadata: Metadata) {
+        require(
+            !_is_metadata_key_frozen(self, asset, key),
+            SetMetadataError::Frozen,
+        );
+        validate_metadata(self.slot(), key, metadata);
         let hashed_key = sha256((asset, key));
 
-        match metadata {
-            Metadata::Int(data) => {
-                write(hashed_key, 0, data);
-                write(sha256((hashed_key, self.slot())), 0, 1);
-            },
-            Metadata::B256(data) => {
-                write(hashed_key, 0, data);
-                write(sha256((hashed_key, self.slot())), 0, 2);
+        // Only record the key the first time metadata is stored under it.
+        if read::<u64>(sha256((hashed_key, self.slot())), 0).is_none() {
+            push_metadata_key(self.slot(), asset, hashed_key, key);
+        }
+
+        write_metadata(self.slot(), hashed_key, metadata);
+
+        log(SetMetadataEvent {
+            asset,
+            metadata: Some(metadata),
+            key,
+            sender: msg_sender().unwrap(),
+        });
+    }
+
+    /// Returns metadata for a specific asset and key pair.
+    ///
+    /// # Additional Information
+    ///
+    /// If no metadata is stored for the asset and key pair, the default metadata for `key` is returned.
+    /// When the metadata of `asset` or `key` is frozen, the default metadata is copied to `asset` at the time of freezing, so later changes to the default metadata are not returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset for the metadata to be queried.
+    /// * `key`: [String] - The key for the metadata to be queried.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Metadata>] - The stored metadata, the default metadata or `None`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `6`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use standards::src7::Metadata;
+    /// use asset::metadata::*;
+    /// use std::string::String;
+    ///
+    /// storage {
+    ///     metadata: StorageMetadata = StorageMetadata {}
+    /// }
+    ///
+    /// fn foo(asset: AssetId, key: String) {
+    ///     let returned_metadata = storage.metadata.get(asset, key, metadata);
+    ///     assert(returned_metadata.is_some());
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn get(self, asset: AssetId, key: String) -> Option<Metadata> {
+        match read_metadata(self.slot(), sha256((asset, key))) {
+            Some(metadata) => Some(metadata),
+            None => {
+                if _is_metadata_key_frozen(self, asset, key) {
+                    return None;
+                }
+
+                read_metadata(self.slot(), default_hashed_key(key))
             },
-            Metadata::String(data) => {
-                require(!data.is_empty(), SetMetadataError::EmptyString);
+        }
+    }
+
+    /// Stores default metadata for a key which applies to all assets.
+    ///
+    /// # Additional Information
+    ///
+    /// The default metadata is returned by `get()` for any asset which does not have metadata stored for `key`, unless the metadata of the asset or `key` has been frozen.
+    /// Frozen assets keep the default metadata which was stored at the time of freezing.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [String] - The key for the default metadata to be stored.
+    /// * `metadata`: [Metadata] - The default metadata which to be stored.
+    ///
+    /// # Reverts
+    ///
+    /// * When the metadata is an empty string.
+    /// * When the metadata is an empty bytes.
+    /// * When the metadata does not conform to the schema registered for `key`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `3`
+    /// * Writes: `4`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use standards::src7::Metadata;
+    /// use asset::metadata::*;
+    /// use std::string::String;
+    ///
+    /// storage {
+    ///     metadata: StorageMetadata = StorageMetadata {}
+    /// }
+    ///
+    /// fn foo(asset: AssetId) {
+    ///     let key = String::from_ascii_str("collection");
+    ///     storage.metadata.insert_default(key, Metadata::String(String::from_ascii_str("Fuel")));
+    ///     assert(storage.metadata.get(asset, key).is_some());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn insert_default(self, key: String, metadata: Metadata) {
+        validate_metadata(self.slot(), key, metadata);
+        let hashed_key = default_hashed_key(key);
+
+        // Only record the key the first time default metadata is stored under it.
+        if read::<u64>(sha256((hashed_key, self.slot())), 0).is_none() {
+            push_default_metadata_key(self.slot(), key);
+        }
+
+        write_metadata(self.slot(), hashed_key, metadata);
+
+        log(SetDefaultMetadata {
+            key,
+            metadata,
+            sender: msg_sender().unwrap(),
+        });
+    }
+
+    /// Returns the default metadata for a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key`: [String] - The key for the default metadata to be queried.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<Metadata>] - The default metadata or `None`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `2`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use standards::src7::Metadata;
+    /// use asset::metadata::*;
+    /// use std::string::String;
+    ///
+    /// storage {
+    ///     metadata: StorageMetadata = StorageMetadata {}
+    /// }
+    ///
+    /// fn foo() {
+    ///     let default: Option<Metadata> = storage.metadata.get_default(String::from_ascii_str("collection"));
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn get_default(self, key: String) -> Option<Metadata> {
+        read_metadata(self.slot(), default_hashed_key(key))
+    }
+
+    /// Returns whether the metadata returned by `get()` for an asset and key pair is the default metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset for the metadata to be queried.
+    /// * `key`: [String] - The key for the metadata to be queried.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if no metadata is stored for `asset` and `key`, the metadata is not frozen, and default metadata is stored for `key`, otherwise `false`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `4`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use asset::metadata::*;
+    /// use std::string::String;
+    ///
+    /// storage {
+    ///     metadata: StorageMetadata = StorageMetadata {}
+    /// }
+    ///
+    /// fn foo(asset: AssetId) {
+    ///     let is_default = storage.metadata.is_default(asset, String::from_ascii_str("collection"));
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn is_default(self, asset: AssetId, key: String) -> bool {
+        let type_key = sha256((sha256((asset, key)), self.slot()));
+        let default_type_key = sha256((default_hashed_key(key), self.slot()));
+
+        if read::<u64>(type_key, 0).is_some() || read::<u64>(default_type_key, 0).is_none() {
+            return false;
+        }
+
+        !_is_metadata_key_frozen(self, asset, key)
+    }
+
+    /// Removes metadata for a specific asset and key pair.
+    ///
+    /// # Additional Information
+    ///
+    /// Both the type of the metadata and the underlying data are cleared, and the key is removed from the asset's metadata keys. If no metadata is stored for the asset and key pair, no event is logged.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset for the metadata to be removed.
+    /// * `key`: [String] - The key for the metadata to be removed.
+    ///
+    /// # Returns
+    ///
+    /// * [bool] - `true` if metadata was removed, otherwise `false`.
+    ///
+    /// # Reverts
+    ///
+    /// * When the metadata of `asset` or `key` has been frozen.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `6`
+    /// * Writes: `7`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use asset::metadata::*;
+    /// use std::string::String;
+    ///
+    /// storage {
+    ///     metadata: StorageMetadata = StorageMetadata {}
+    /// }
+    ///
+    /// fn foo(asset: AssetId, key: String) {
+    ///     let _ = storage.metadata.remove(asset, key);
+    ///     assert(storage.metadata.get(asset, key).is_none());
+    /// }
+    /// ```
+    #[storage(read, write)]
+    pub fn remove(self, asset: AssetId, key: String) -> bool {
+        require(
+            !_is_metadata_key_frozen(self, asset, key),
+            SetMetadataError::Frozen,
+        );
+        let hashed_key = sha256((asset, key));
+        let type_key = sha256((hashed_key, self.slot()));
 
+        match read::<u64>(type_key, 0) {
+            Some(1) => {
+                let _ = clear::<u64>(hashed_key, 0);
+            },
+            Some(2) => {
+                let _ = clear::<b256>(hashed_key, 0);
+            },
+            Some(3) => {
                 let storage_string: StorageKey<StorageString> = StorageKey::new(hashed_key, 0, hashed_key);
-                storage_string.write_slice(data);
-                write(sha256((hashed_key, self.slot())), 0, 3);
+                let _ = storage_string.clear();
             },
-            Metadata::Bytes(data) => {
-                require(!data.is_empty(), SetMetadataError::EmptyBytes);
-
+            Some(4) => {
                 let storage_bytes: StorageKey<StorageBytes> = StorageKey::new(hashed_key, 0, hashed_key);
-                storage_bytes.write_slice(data);
-                write(sha256((hashed_key, self.slot())), 0, 4);
+                let _ = storage_bytes.clear();
+            },
+            _ => {
+                return false;
             },
         }
+        let _ = clear::<u64>(type_key, 0);
+        remove_metadata_key(self.slot(), asset, hashed_key);
+
+        log(SetMetadataEvent {
+            asset,
+            metadata: None,
+            key,
+            sender: msg_sender().unwrap(),
+        });
+
+        true
+    }
+
+    /// Returns the number of keys under which metadata is stored for an asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset of which to count the metadata keys.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The number of metadata keys of `asset`.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use asset::metadata::*;
+    ///
+    /// storage {
+    ///     metadata: StorageMetadata = StorageMetadata {}
+    /// }
+    ///
+    /// fn foo(asset: AssetId) {
+    ///     let mut index = 0;
+    ///     while index < storage.metadata.metadata_keys_len(asset) {
+    ///         let key = storage.metadata.metadata_key_at(asset, index).unwrap();
+    ///         index += 1;
+    ///     }
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn metadata_keys_len(self, asset: AssetId) -> u64 {
+        read::<u64>(metadata_keys_len_key(self.slot(), asset), 0).unwrap_or(0)
+    }
+
+    /// Returns the key at the given index under which metadata is stored for an asset.
+    ///
+    /// # Additional Information
+    ///
+    /// Removing metadata moves the last key to the index of the removed key, so the order of keys is not preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset`: [AssetId] - The asset of which to return the metadata key.
+    /// * `index`: [u64] - The index of the metadata key.
+    ///
+    /// # Returns
+    ///
+    /// * [Option<String>] - The metadata key at `index`, or `None` if `index` is not less than the number of metadata keys.
+    ///
+    /// # Number of Storage Accesses
+    ///
+    /// * Reads: `1`
+    ///
+    /// # Example
+    ///
+    /// ```sway
+    /// use asset::metadata::*;
+    /// use std::string::String;
+    ///
+    /// storage {
+    ///     metadata: StorageMetadata = StorageMetadata {}
+    /// }
+    ///
+    /// fn foo(asset: AssetId) {
+    ///     let key: Option<String> = storage.metadata.metadata_key_at(asset, 0);
+    /// }
+    /// ```
+    #[storage(read)]
+    pub fn metadata_key_at(self, asset: AssetId, index: u64) -> Option<String> {
+        metadata_key_at_key(self.slot(), asset, index).read_slice()
+    }
+}
+
+/// Stores metadata for a specific asset and key pair.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset for the metadata to be stored.
+/// * `metadata`: [Option<Metadata>] - The metadata which to be stored.
+/// * `key`: [String] - The key for the metadata to be stored.
+///
+/// # Reverts
+///
+/// * When the metadata of `asset` or `key` has been frozen.
+/// * When the metadata does not conform to the schema registered for `key`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `5`
+/// * Writes: `5`
+///
+/// # Example
+///
+/// ```sway
+/// use standards::src7::Metadata;
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId, key: String, metadata: Option<Metadata>) {
+///     _set_metadata(storage.metadata, asset, metadata, key);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_metadata(
+    metadata_key: StorageKey<StorageMetadata>,
+    asset: AssetId,
+    key: String,
+    metadata: Metadata,
+) {
+    metadata_key.insert(asset, key, metadata);
+}
+
+/// Stores metadata for many asset and key pairs.
+///
+/// # Additional Information
+///
+/// A `SetMetadataEvent` is logged for each entry.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `entries`: [Vec<(AssetId, String, Metadata)>] - The assets, keys and metadata which to be stored.
+///
+/// # Reverts
+///
+/// * When the metadata of any asset or key has been frozen.
+/// * When any metadata is an empty string.
+/// * When any metadata is an empty bytes.
+/// * When any metadata does not conform to the schema registered for its key.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `5` per entry
+/// * Writes: `5` per entry
+///
+/// # Example
+///
+/// ```sway
+/// use standards::src7::Metadata;
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset_1: AssetId, asset_2: AssetId) {
+///     let key = String::from_ascii_str("image");
+///     let mut entries = Vec::new();
+///     entries.push((asset_1, key, Metadata::String(String::from_ascii_str("ipfs://1"))));
+///     entries.push((asset_2, key, Metadata::String(String::from_ascii_str("ipfs://2"))));
+///
+///     _set_metadata_batch(storage.metadata, entries);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_metadata_batch(
+    metadata_key: StorageKey<StorageMetadata>,
+    entries: Vec<(AssetId, String, Metadata)>,
+) {
+    let mut i = 0;
+    while i < entries.len() {
+        let (asset, key, metadata) = entries.get(i).unwrap();
+        metadata_key.insert(asset, key, metadata);
+        i += 1;
+    }
+}
+
+/// Removes metadata for a specific asset and key pair.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset for the metadata to be removed.
+/// * `key`: [String] - The key for the metadata to be removed.
+///
+/// # Returns
+///
+/// * [bool] - `true` if metadata was removed, otherwise `false`.
+///
+/// # Reverts
+///
+/// * When the metadata of `asset` or `key` has been frozen.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `6`
+/// * Writes: `7`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId, key: String) {
+///     let removed = _remove_metadata(storage.metadata, asset, key);
+///     assert(_metadata(storage.metadata, asset, key).is_none());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _remove_metadata(
+    metadata_key: StorageKey<StorageMetadata>,
+    asset: AssetId,
+    key: String,
+) -> bool {
+    metadata_key.remove(asset, key)
+}
+
+/// Returns metadata for a specific asset and key pair.
+///
+/// # Additional Information
+///
+/// If no metadata is stored for the asset and key pair, the default metadata for `key` is returned.
+/// When the metadata of `asset` or `key` is frozen, the default metadata at the time of freezing is returned instead.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset for the metadata to be read.
+/// * `metadata`: [Option<Metadata>] - The metadata which to be read.
+/// * `key`: [String] - The key for the metadata to be read.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `6`
+///
+/// # Example
+///
+/// ```sway
+/// use standards::src7::Metadata;
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId, key: String) {
+///     let result: Option<Metadata> = _metadata(storage.metadata, asset, key);
+/// }
+/// ```
+#[storage(read)]
+pub fn _metadata(
+    metadata_key: StorageKey<StorageMetadata>,
+    asset: AssetId,
+    key: String,
+) -> Option<Metadata> {
+    metadata_key.get(asset, key)
+}
+
+/// Unconditionally stores default metadata for a key which applies to all assets.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `key`: [String] - The key for the default metadata to be stored.
+/// * `metadata`: [Metadata] - The default metadata which to be stored.
+///
+/// # Reverts
+///
+/// * When the metadata is an empty string.
+/// * When the metadata is an empty bytes.
+/// * When the metadata does not conform to the schema registered for `key`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `3`
+/// * Writes: `4`
+///
+/// # Example
+///
+/// ```sway
+/// use standards::src7::Metadata;
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(key: String, metadata: Metadata) {
+///     _set_default_metadata(storage.metadata, key, metadata);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_default_metadata(
+    metadata_key: StorageKey<StorageMetadata>,
+    key: String,
+    metadata: Metadata,
+) {
+    metadata_key.insert_default(key, metadata);
+}
+
+/// Returns the default metadata for a key.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `key`: [String] - The key for the default metadata to be read.
+///
+/// # Returns
+///
+/// * [Option<Metadata>] - The default metadata or `None`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+///
+/// # Example
+///
+/// ```sway
+/// use standards::src7::Metadata;
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(key: String) {
+///     let result: Option<Metadata> = _default_metadata(storage.metadata, key);
+/// }
+/// ```
+#[storage(read)]
+pub fn _default_metadata(
+    metadata_key: StorageKey<StorageMetadata>,
+    key: String,
+) -> Option<Metadata> {
+    metadata_key.get_default(key)
+}
+
+/// Returns whether the metadata for an asset and key pair is the default metadata.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset for the metadata to be checked.
+/// * `key`: [String] - The key for the metadata to be checked.
+///
+/// # Returns
+///
+/// * [bool] - `true` if no metadata is stored for `asset` and `key`, the metadata is not frozen, and default metadata is stored for `key`, otherwise `false`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `4`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId, key: String) {
+///     if _is_default_metadata(storage.metadata, asset, key) {
+///         // The metadata is shared by all assets
+///     }
+/// }
+/// ```
+#[storage(read)]
+pub fn _is_default_metadata(
+    metadata_key: StorageKey<StorageMetadata>,
+    asset: AssetId,
+    key: String,
+) -> bool {
+    metadata_key.is_default(asset, key)
+}
+
+/// Returns metadata for many asset and key pairs.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `queries`: [Vec<(AssetId, String)>] - The asset and key pairs for the metadata to be read.
+///
+/// # Returns
+///
+/// * [Vec<Option<Metadata>>] - The stored metadata or `None` for each pair, in the same order as `queries`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `6` per query
+///
+/// # Example
+///
+/// ```sway
+/// use standards::src7::Metadata;
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     let mut queries = Vec::new();
+///     queries.push((asset, String::from_ascii_str("image")));
+///     queries.push((asset, String::from_ascii_str("description")));
+///
+///     let results: Vec<Option<Metadata>> = _metadata_batch(storage.metadata, queries);
+///     assert(results.len() == 2);
+/// }
+/// ```
+#[storage(read)]
+pub fn _metadata_batch(
+    metadata_key: StorageKey<StorageMetadata>,
+    queries: Vec<(AssetId, String)>,
+) -> Vec<Option<Metadata>> {
+    let mut results: Vec<Option<Metadata>> = Vec::with_capacity(queries.len());
+    let mut i = 0;
+    while i < queries.len() {
+        let (asset, key) = queries.get(i).unwrap();
+        results.push(metadata_key.get(asset, key));
+        i += 1;
+    }
+
+    results
+}
+
+/// Returns the number of keys under which metadata is stored for an asset.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset of which to count the metadata keys.
+///
+/// # Returns
+///
+/// * [u64] - The number of metadata keys of `asset`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::*;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     let len = _metadata_keys_len(storage.metadata, asset);
+/// }
+/// ```
+#[storage(read)]
+pub fn _metadata_keys_len(metadata_key: StorageKey<StorageMetadata>, asset: AssetId) -> u64 {
+    metadata_key.metadata_keys_len(asset)
+}
+
+/// Returns the key at the given index under which metadata is stored for an asset.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset of which to return the metadata key.
+/// * `index`: [u64] - The index of the metadata key.
+///
+/// # Returns
+///
+/// * [Option<String>] - The metadata key at `index`, or `None` if `index` is not less than the number of metadata keys.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::*;
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     let key: Option<String> = _metadata_key_at(storage.metadata, asset, 0);
+/// }
+/// ```
+#[storage(read)]
+pub fn _metadata_key_at(
+    metadata_key: StorageKey<StorageMetadata>,
+    asset: AssetId,
+    index: u64,
+) -> Option<String> {
+    metadata_key.metadata_key_at(asset, index)
+}
+
+/// Freezes all metadata and attributes of an asset.
+///
+/// # Additional Information
+///
+/// Once frozen, `_set_name()`, `_set_symbol()`, `_set_decimals()`, `_set_metadata()` and `_remove_metadata()` revert for `asset` when passed the same `metadata_key`. Freezing cannot be undone.
+/// Default metadata for keys without metadata stored for `asset` is copied to `asset`, so the metadata of `asset` does not change when the default metadata changes.
+/// If the metadata of `asset` is already frozen, no storage is written and no event is logged.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset of which to freeze the metadata.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2` + `5` per default metadata key
+/// * Writes: `1` + `5` per default metadata key copied to `asset`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::{_freeze_metadata, _is_metadata_frozen, StorageMetadata};
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     _freeze_metadata(storage.metadata, asset);
+///     assert(_is_metadata_frozen(storage.metadata, asset));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _freeze_metadata(metadata_key: StorageKey<StorageMetadata>, asset: AssetId) {
+    if _is_metadata_frozen(metadata_key, asset) {
+        return;
+    }
 
-        log(SetMetadataEvent {
-            asset,
-            metadata: Some(metadata),
-            key,
-            sender: msg_sender().unwrap(),
-        });
+    let slot = metadata_key.slot();
+    let len = read::<u64>(default_metadata_keys_len_key(slot), 0).unwrap_or(0);
+    let mut index = 0;
+    while index < len {
+        let key = default_metadata_key_at_key(slot, index).read_slice().unwrap();
+        copy_default_metadata(slot, asset, key);
+        index += 1;
     }
 
-    /// Returns metadata for a specific asset and key pair.
-    ///
-    /// # Arguments
-    ///
-    /// * `asset`: [AssetId] - The asset for the metadata to be queried.
-    /// * `key`: [String] - The key for the metadata to be queried.
-    ///
-    /// # Returns
-    ///
-    /// * [Option<Metadata>] - The stored metadata or `None`.
-    ///
-    /// # Number of Storage Accesses
-    ///
-    /// * Reads: `2`
-    ///
-    /// # Example
-    ///
-    /// ```sway
-    /// use standards::src7::Metadata;
-    /// use asset::metadata::*;
-    /// use std::string::String;
-    ///
-    /// storage {
-    ///     metadata: StorageMetadata = StorageMetadata {}
-    /// }
-    ///
-    /// fn foo(asset: AssetId, key: String) {
-    ///     let returned_metadata = storage.metadata.get(asset, key, metadata);
-    ///     assert(returned_metadata.is_some());
-    /// }
-    /// ```
-    #[storage(read)]
-    pub fn get(self, asset: AssetId, key: String) -> Option<Metadata> {
-        let hashed_key = sha256((asset, key));
+    metadata_frozen_key(slot, asset).write(true);
 
-        match read::<u64>(sha256((hashed_key, self.slot())), 0) {
-            Some(1) => {
-                Some(Metadata::Int(read::<u64>(hashed_key, 0).unwrap()))
-            },
-            Some(2) => {
-                Some(Metadata::B256(read::<b256>(hashed_key, 0).unwrap()))
-            },
-            Some(3) => {
-                let storage_string: StorageKey<StorageString> = StorageKey::new(hashed_key, 0, hashed_key);
-                Some(Metadata::String(storage_string.read_slice().unwrap_or(String::new())))
-            },
-            Some(4) => {
-                let storage_bytes: StorageKey<StorageBytes> = StorageKey::new(hashed_key, 0, hashed_key);
-                Some(Metadata::Bytes(storage_bytes.read_slice().unwrap_or(Bytes::new())))
-            },
-            _ => None,
-        }
-    }
+    log(MetadataFrozen {
+        asset,
+        key: None,
+        sender: msg_sender().unwrap(),
+    });
 }
 
-/// Unconditionally stores metadata for a specific asset and key pair.
+/// Returns whether all metadata and attributes of an asset are frozen.
 ///
 /// # Arguments
 ///
 /// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
-/// * `asset`: [AssetId] - The asset for the metadata to be stored.
-/// * `metadata`: [Option<Metadata>] - The metadata which to be stored.
-/// * `key`: [String] - The key for the metadata to be stored.
+/// * `asset`: [AssetId] - The asset to check.
+///
+/// # Returns
+///
+/// * [bool] - `true` if the metadata of `asset` has been frozen, otherwise `false`.
 ///
 /// # Number of Storage Accesses
 ///
-/// * Writes: `2`
+/// * Reads: `1`
 ///
 /// # Example
 ///
 /// ```sway
-/// use standards::src7::Metadata;
-/// use asset::metadata::*;
+/// use asset::metadata::{_is_metadata_frozen, StorageMetadata};
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     assert(!_is_metadata_frozen(storage.metadata, asset));
+/// }
+/// ```
+#[storage(read)]
+pub fn _is_metadata_frozen(metadata_key: StorageKey<StorageMetadata>, asset: AssetId) -> bool {
+    metadata_frozen_key(metadata_key.slot(), asset).try_read().unwrap_or(false)
+}
+
+/// Freezes the metadata of an asset stored under a single key.
+///
+/// # Additional Information
+///
+/// Once frozen, `_set_metadata()` and `_remove_metadata()` revert for `asset` and `key` when passed the same `metadata_key`. Freezing cannot be undone.
+/// If no metadata is stored for `asset` and `key`, the default metadata for `key` is copied to `asset`, so the metadata does not change when the default metadata changes.
+/// If the metadata of `asset` or `key` is already frozen, no storage is written and no event is logged.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset of which to freeze the metadata.
+/// * `key`: [String] - The key of the metadata to freeze.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `6`
+/// * Writes: `6`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::{_freeze_metadata_key, _is_metadata_key_frozen, StorageMetadata};
 /// use std::string::String;
 ///
 /// storage {
 ///     metadata: StorageMetadata = StorageMetadata {}
 /// }
 ///
-/// fn foo(asset: AssetId, key: String, metadata: Option<Metadata>) {
-///     _set_metadata(storage.metadata, asset, metadata, key);
+/// fn foo(asset: AssetId) {
+///     let key = String::from_ascii_str("image");
+///     _freeze_metadata_key(storage.metadata, asset, key);
+///     assert(_is_metadata_key_frozen(storage.metadata, asset, key));
 /// }
 /// ```
 #[storage(read, write)]
-pub fn _set_metadata(
+pub fn _freeze_metadata_key(
     metadata_key: StorageKey<StorageMetadata>,
     asset: AssetId,
     key: String,
-    metadata: Metadata,
 ) {
-    metadata_key.insert(asset, key, metadata);
+    if _is_metadata_key_frozen(metadata_key, asset, key) {
+        return;
+    }
+
+    copy_default_metadata(metadata_key.slot(), asset, key);
+    metadata_key_frozen_key(metadata_key.slot(), asset, key).write(true);
+
+    log(MetadataFrozen {
+        asset,
+        key: Some(key),
+        sender: msg_sender().unwrap(),
+    });
 }
 
-/// Returns metadata for a specific asset and key pair.
+/// Returns whether the metadata of an asset stored under a key is frozen.
+///
+/// # Additional Information
+///
+/// This returns `true` if either the single key or all metadata of the asset has been frozen.
 ///
 /// # Arguments
 ///
 /// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
-/// * `asset`: [AssetId] - The asset for the metadata to be read.
-/// * `metadata`: [Option<Metadata>] - The metadata which to be read.
-/// * `key`: [String] - The key for the metadata to be read.
+/// * `asset`: [AssetId] - The asset to check.
+/// * `key`: [String] - The key of the metadata to check.
+///
+/// # Returns
+///
+/// * [bool] - `true` if the metadata of `asset` under `key` has been frozen, otherwise `false`.
 ///
 /// # Number of Storage Accesses
 ///
@@ -206,26 +1047,149 @@ pub fn _set_metadata(
 /// # Example
 ///
 /// ```sway
-/// use standards::src7::Metadata;
-/// use asset::metadata::*;
+/// use asset::metadata::{_is_metadata_key_frozen, StorageMetadata};
 /// use std::string::String;
 ///
 /// storage {
 ///     metadata: StorageMetadata = StorageMetadata {}
 /// }
 ///
-/// fn foo(asset: AssetId, key: String) {
-///     let result: Option<Metadata> = _metadata(storage.metadata, asset, key);
+/// fn foo(asset: AssetId) {
+///     assert(!_is_metadata_key_frozen(storage.metadata, asset, String::from_ascii_str("image")));
 /// }
 /// ```
 #[storage(read)]
-pub fn _metadata(
+pub fn _is_metadata_key_frozen(
     metadata_key: StorageKey<StorageMetadata>,
     asset: AssetId,
     key: String,
-) -> Option<Metadata> {
-    metadata_key.get(asset, key)
+) -> bool {
+    if _is_metadata_frozen(metadata_key, asset) {
+        return true;
+    }
+
+    metadata_key_frozen_key(metadata_key.slot(), asset, key).try_read().unwrap_or(false)
+}
+
+/// Registers the schema which all metadata stored under a key must conform to.
+///
+/// # Additional Information
+///
+/// Once registered, `_set_metadata()` and `_set_default_metadata()` revert if the metadata under `key` is not of the
+/// registered type or is longer than the registered maximum length. Metadata stored before the schema was registered
+/// is not affected. Any previously registered schema for `key` is replaced.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `key`: [String] - The key for which to register the schema.
+/// * `schema`: [MetadataSchema] - The schema which metadata under `key` must conform to.
+///
+/// # Number of Storage Accesses
+///
+/// * Writes: `1`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::{_metadata_schema, _set_metadata_schema, MetadataSchema, MetadataType, StorageMetadata};
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo() {
+///     let key = String::from_ascii_str("image");
+///     _set_metadata_schema(storage.metadata, key, MetadataSchema {
+///         metadata_type: MetadataType::String,
+///         max_length: Some(256),
+///     });
+///     assert(_metadata_schema(storage.metadata, key).is_some());
+/// }
+/// ```
+#[storage(write)]
+pub fn _set_metadata_schema(
+    metadata_key: StorageKey<StorageMetadata>,
+    key: String,
+    schema: MetadataSchema,
+) {
+    schema_key(metadata_key.slot(), key).write(schema);
+}
+
+/// Removes the schema registered for a key.
+///
+/// # Additional Information
+///
+/// Once removed, metadata of any type may be stored under `key` again.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `key`: [String] - The key of which to remove the schema.
+///
+/// # Number of Storage Accesses
+///
+/// * Writes: `1`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::{_metadata_schema, _remove_metadata_schema, StorageMetadata};
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo() {
+///     let key = String::from_ascii_str("image");
+///     _remove_metadata_schema(storage.metadata, key);
+///     assert(_metadata_schema(storage.metadata, key).is_none());
+/// }
+/// ```
+#[storage(write)]
+pub fn _remove_metadata_schema(metadata_key: StorageKey<StorageMetadata>, key: String) {
+    let _ = schema_key(metadata_key.slot(), key).clear();
 }
+
+/// Returns the schema registered for a key.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `key`: [String] - The key of which to return the schema.
+///
+/// # Returns
+///
+/// * [Option<MetadataSchema>] - The registered schema or `None`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Example
+///
+/// ```sway
+/// use asset::metadata::{_metadata_schema, StorageMetadata};
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {}
+/// }
+///
+/// fn foo() {
+///     assert(_metadata_schema(storage.metadata, String::from_ascii_str("image")).is_none());
+/// }
+/// ```
+#[storage(read)]
+pub fn _metadata_schema(
+    metadata_key: StorageKey<StorageMetadata>,
+    key: String,
+) -> Option<MetadataSchema> {
+    schema_key(metadata_key.slot(), key).try_read()
+}
+
 abi SetAssetMetadata {
     /// Stores metadata for a specific asset and key pair.
     ///
@@ -473,3 +1437,203 @@ impl Metadata {
         }
     }
 }
+
+fn metadata_keys_len_key(slot: b256, asset: AssetId) -> b256 {
+    sha256(("metadata_keys_len", asset, slot))
+}
+
+fn metadata_key_index_key(slot: b256, hashed_key: b256) -> b256 {
+    sha256(("metadata_key_index", hashed_key, slot))
+}
+
+fn metadata_key_at_key(slot: b256, asset: AssetId, index: u64) -> StorageKey<StorageString> {
+    let key_digest = sha256(("metadata_key", asset, index, slot));
+    StorageKey::new(key_digest, 0, key_digest)
+}
+
+#[storage(read, write)]
+fn push_metadata_key(slot: b256, asset: AssetId, hashed_key: b256, key: String) {
+    let len_key = metadata_keys_len_key(slot, asset);
+    let len = read::<u64>(len_key, 0).unwrap_or(0);
+
+    metadata_key_at_key(slot, asset, len).write_slice(key);
+    // Indices are stored offset by one so that an unset slot can be distinguished from index zero.
+    write(metadata_key_index_key(slot, hashed_key), 0, len + 1);
+    write(len_key, 0, len + 1);
+}
+
+#[storage(read, write)]
+fn remove_metadata_key(slot: b256, asset: AssetId, hashed_key: b256) {
+    let index_key = metadata_key_index_key(slot, hashed_key);
+    let index = match read::<u64>(index_key, 0) {
+        Some(index) => index - 1,
+        None => return,
+    };
+
+    let len_key = metadata_keys_len_key(slot, asset);
+    let last_index = read::<u64>(len_key, 0).unwrap() - 1;
+
+    // Move the last key into the removed key's position.
+    if index != last_index {
+        let last_key = metadata_key_at_key(slot, asset, last_index).read_slice().unwrap();
+        metadata_key_at_key(slot, asset, index).write_slice(last_key);
+        write(
+            metadata_key_index_key(slot, sha256((asset, last_key))),
+            0,
+            index + 1,
+        );
+    }
+
+    let _ = metadata_key_at_key(slot, asset, last_index).clear();
+    let _ = clear::<u64>(index_key, 0);
+    write(len_key, 0, last_index);
+}
+
+fn default_metadata_keys_len_key(slot: b256) -> b256 {
+    sha256(("default_metadata_keys_len", slot))
+}
+
+fn default_metadata_key_at_key(slot: b256, index: u64) -> StorageKey<StorageString> {
+    let key_digest = sha256(("default_metadata_key", index, slot));
+    StorageKey::new(key_digest, 0, key_digest)
+}
+
+#[storage(read, write)]
+fn push_default_metadata_key(slot: b256, key: String) {
+    let len_key = default_metadata_keys_len_key(slot);
+    let len = read::<u64>(len_key, 0).unwrap_or(0);
+
+    default_metadata_key_at_key(slot, len).write_slice(key);
+    write(len_key, 0, len + 1);
+}
+
+#[storage(read, write)]
+fn copy_default_metadata(slot: b256, asset: AssetId, key: String) {
+    let hashed_key = sha256((asset, key));
+    if read::<u64>(sha256((hashed_key, slot)), 0).is_some() {
+        return;
+    }
+
+    match read_metadata(slot, default_hashed_key(key)) {
+        Some(metadata) => {
+            push_metadata_key(slot, asset, hashed_key, key);
+            write_metadata(slot, hashed_key, metadata);
+        },
+        None => (),
+    }
+}
+
+fn metadata_frozen_key(slot: b256, asset: AssetId) -> StorageKey<bool> {
+    let key_digest = sha256(("metadata_frozen", asset, slot));
+    StorageKey::new(key_digest, 0, key_digest)
+}
+
+fn metadata_key_frozen_key(slot: b256, asset: AssetId, key: String) -> StorageKey<bool> {
+    let key_digest = sha256(("metadata_frozen", asset, key, slot));
+    StorageKey::new(key_digest, 0, key_digest)
+}
+
+fn schema_key(slot: b256, key: String) -> StorageKey<MetadataSchema> {
+    let key_digest = sha256(("metadata_schema", key, slot));
+    StorageKey::new(key_digest, 0, key_digest)
+}
+
+#[storage(read)]
+fn validate_metadata(slot: b256, key: String, metadata: Metadata) {
+    let schema = match schema_key(slot, key).try_read() {
+        Some(schema) => schema,
+        None => return,
+    };
+
+    let (matches_type, length) = match metadata {
+        Metadata::Int(_) => {
+            let matches_type = match schema.metadata_type {
+                MetadataType::Int => true,
+                _ => false,
+            };
+            (matches_type, None)
+        },
+        Metadata::B256(_) => {
+            let matches_type = match schema.metadata_type {
+                MetadataType::B256 => true,
+                _ => false,
+            };
+            (matches_type, None)
+        },
+        Metadata::String(data) => {
+            let matches_type = match schema.metadata_type {
+                MetadataType::String => true,
+                _ => false,
+            };
+            (matches_type, Some(data.as_bytes().len()))
+        },
+        Metadata::Bytes(data) => {
+            let matches_type = match schema.metadata_type {
+                MetadataType::Bytes => true,
+                _ => false,
+            };
+            (matches_type, Some(data.len()))
+        },
+    };
+    require(matches_type, SetMetadataError::TypeMismatch);
+
+    match (length, schema.max_length) {
+        (Some(length), Some(max_length)) => {
+            require(length <= max_length, SetMetadataError::MaxLengthExceeded);
+        },
+        _ => {},
+    }
+}
+
+fn default_hashed_key(key: String) -> b256 {
+    sha256(("default_metadata", key))
+}
+
+#[storage(read, write)]
+fn write_metadata(slot: b256, hashed_key: b256, metadata: Metadata) {
+    match metadata {
+        Metadata::Int(data) => {
+            write(hashed_key, 0, data);
+            write(sha256((hashed_key, slot)), 0, 1);
+        },
+        Metadata::B256(data) => {
+            write(hashed_key, 0, data);
+            write(sha256((hashed_key, slot)), 0, 2);
+        },
+        Metadata::String(data) => {
+            require(!data.is_empty(), SetMetadataError::EmptyString);
+
+            let storage_string: StorageKey<StorageString> = StorageKey::new(hashed_key, 0, hashed_key);
+            storage_string.write_slice(data);
+            write(sha256((hashed_key, slot)), 0, 3);
+        },
+        Metadata::Bytes(data) => {
+            require(!data.is_empty(), SetMetadataError::EmptyBytes);
+
+            let storage_bytes: StorageKey<StorageBytes> = StorageKey::new(hashed_key, 0, hashed_key);
+            storage_bytes.write_slice(data);
+            write(sha256((hashed_key, slot)), 0, 4);
+        },
+    }
+}
+
+#[storage(read)]
+fn read_metadata(slot: b256, hashed_key: b256) -> Option<Metadata> {
+    match read::<u64>(sha256((hashed_key, slot)), 0) {
+        Some(1) => {
+            Some(Metadata::Int(read::<u64>(hashed_key, 0).unwrap()))
+        },
+        Some(2) => {
+            Some(Metadata::B256(read::<b256>(hashed_key, 0).unwrap()))
+        },
+        Some(3) => {
+            let storage_string: StorageKey<StorageString> = StorageKey::new(hashed_key, 0, hashed_key);
+            Some(Metadata::String(storage_string.read_slice().unwrap_or(String::new())))
+        },
+        Some(4) => {
+            let storage_bytes: StorageKey<StorageBytes> = StorageKey::new(hashed_key, 0, hashed_key);
+            Some(Metadata::Bytes(storage_bytes.read_slice().unwrap_or(Bytes::new())))
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,236 @@
+library;
+
+use ::errors::NftError;
+use ::base::_total_supply;
+use ::metadata::{_metadata, _set_metadata, StorageMetadata};
+use ::supply::{_burn, _mint, _set_max_supply};
+use std::{hash::Hash, string::String};
+use src7::Metadata;
+
+/// Mints a non-fungible asset using the `sub_id` sub-identifier.
+///
+/// # Additional Information
+///
+/// A non-fungible asset has a total supply of exactly one coin and may only ever be minted once, even after it has been burned.
+/// The maximum supply of the asset is set to one, so `_mint()` cannot increase its supply above one when passed the same `max_supply_key`.
+///
+/// # Arguments
+///
+/// * `total_assets_key`: [StorageKey<u64>] - The location in storage that the `u64` which represents the total assets is stored.
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `max_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the maximum supply of assets is stored.
+/// * `recipient`: [Identity] - The user to which the newly minted asset is transferred to.
+/// * `sub_id`: [SubId] - The sub-identifier of the newly minted asset.
+///
+/// # Returns
+///
+/// * [AssetId] - The `AssetId` of the newly minted asset.
+///
+/// # Reverts
+///
+/// * When the asset with the given `sub_id` has already been minted.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `6`
+/// * Writes: `6`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::nft::{_is_nft_minted, _mint_nft};
+///
+/// storage {
+///     total_assets: u64 = 0,
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+///     max_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(recipient: Identity) {
+///     let asset_id = _mint_nft(storage.total_assets, storage.total_supply, storage.max_supply, recipient, SubId::zero());
+///     assert(_is_nft_minted(storage.total_supply, asset_id));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _mint_nft(
+    total_assets_key: StorageKey<u64>,
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    max_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    recipient: Identity,
+    sub_id: SubId,
+) -> AssetId {
+    let asset_id = AssetId::new(ContractId::this(), sub_id);
+    let supply = _total_supply(total_supply_key, asset_id);
+    require(supply.is_none(), NftError::AlreadyMinted);
+
+    _set_max_supply(max_supply_key, asset_id, 1);
+    _mint(
+        total_assets_key,
+        total_supply_key,
+        max_supply_key,
+        recipient,
+        sub_id,
+        1,
+    )
+}
+
+/// Burns the non-fungible asset with the given `sub_id`.
+///
+/// # Additional Information
+///
+/// **Warning** This function burns the asset unequivocally. It does not check that the asset is sent to the calling contract.
+/// **Note:** A burned non-fungible asset cannot be minted again.
+///
+/// # Arguments
+///
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `sub_id`: [SubId] - The sub-identifier of the asset to burn.
+///
+/// # Reverts
+///
+/// * When the calling contract does not own the asset.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `3`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::nft::{_burn_nft, _is_nft_minted};
+///
+/// storage {
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(asset_id: AssetId) {
+///     _burn_nft(storage.total_supply, SubId::zero());
+///     assert(!_is_nft_minted(storage.total_supply, asset_id));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _burn_nft(total_supply_key: StorageKey<StorageMap<AssetId, u64>>, sub_id: SubId) {
+    _burn(total_supply_key, sub_id, 1);
+}
+
+/// Returns whether a non-fungible asset is currently in circulation.
+///
+/// # Arguments
+///
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `asset`: [AssetId] - The asset of which to check.
+///
+/// # Returns
+///
+/// * [bool] - `true` if `asset` has been minted and not burned, otherwise `false`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::nft::_is_nft_minted;
+///
+/// storage {
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     assert(!_is_nft_minted(storage.total_supply, asset));
+/// }
+/// ```
+#[storage(read)]
+pub fn _is_nft_minted(
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    asset: AssetId,
+) -> bool {
+    match _total_supply(total_supply_key, asset) {
+        Some(supply) => supply == 1,
+        None => false,
+    }
+}
+
+/// Stores the token URI of a non-fungible asset as SRC-7 metadata.
+///
+/// # Additional Information
+///
+/// The URI is stored as `Metadata::String` under the `uri` key, so it is returned by the SRC-7 `metadata()` function.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset of which to set the token URI.
+/// * `uri`: [String] - The token URI.
+///
+/// # Number of Storage Accesses
+///
+/// * Writes: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::{metadata::StorageMetadata, nft::{_set_token_uri, _token_uri}};
+/// use std::string::String;
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {},
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     let uri = String::from_ascii_str("ipfs://example");
+///     _set_token_uri(storage.metadata, asset, uri);
+///     assert(_token_uri(storage.metadata, asset).unwrap() == uri);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_token_uri(
+    metadata_key: StorageKey<StorageMetadata>,
+    asset: AssetId,
+    uri: String,
+) {
+    _set_metadata(
+        metadata_key,
+        asset,
+        String::from_ascii_str("uri"),
+        Metadata::String(uri),
+    );
+}
+
+/// Returns the token URI of a non-fungible asset.
+///
+/// # Arguments
+///
+/// * `metadata_key`: [StorageKey<StorageMetadata>] - The storage location for metadata.
+/// * `asset`: [AssetId] - The asset of which to query the token URI.
+///
+/// # Returns
+///
+/// * [Option<String>] - The token URI of `asset`, or `None` if it has not been set.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::{metadata::StorageMetadata, nft::_token_uri};
+///
+/// storage {
+///     metadata: StorageMetadata = StorageMetadata {},
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     assert(_token_uri(storage.metadata, asset).is_none());
+/// }
+/// ```
+#[storage(read)]
+pub fn _token_uri(metadata_key: StorageKey<StorageMetadata>, asset: AssetId) -> Option<String> {
+    match _metadata(metadata_key, asset, String::from_ascii_str("uri")) {
+        Some(metadata) => metadata.as_string(),
+        None => None,
+    }
+}
@@ -0,0 +1,117 @@
+library;
+
+use std::{hash::{Hash, sha256}, string::String};
+
+/// Derives a sub-identifier from a serial number.
+///
+/// # Additional Information
+///
+/// The sub-identifier is the SHA-256 hash of the bytes of `"serial"` followed by `serial` encoded as 8 big-endian bytes.
+///
+/// # Arguments
+///
+/// * `serial`: [u64] - The serial number of the asset.
+///
+/// # Returns
+///
+/// * [SubId] - The sub-identifier derived from `serial`.
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::sub_id::sub_id_from_serial;
+///
+/// fn foo() {
+///     assert(sub_id_from_serial(1) != sub_id_from_serial(2));
+/// }
+/// ```
+pub fn sub_id_from_serial(serial: u64) -> SubId {
+    sha256(("serial", serial))
+}
+
+/// Derives a sub-identifier from a string.
+///
+/// # Additional Information
+///
+/// The sub-identifier is the SHA-256 hash of the bytes of `"string"` followed by the UTF-8 bytes of `name`.
+///
+/// # Arguments
+///
+/// * `name`: [String] - The name of the asset.
+///
+/// # Returns
+///
+/// * [SubId] - The sub-identifier derived from `name`.
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::sub_id::sub_id_from_string;
+/// use std::string::String;
+///
+/// fn foo() {
+///     let sub_id = sub_id_from_string(String::from_ascii_str("Gold"));
+///     assert(sub_id != SubId::zero());
+/// }
+/// ```
+pub fn sub_id_from_string(name: String) -> SubId {
+    sha256(("string", name))
+}
+
+/// Derives a sub-identifier from a collection and the index of an asset within it.
+///
+/// # Additional Information
+///
+/// The sub-identifier is the SHA-256 hash of the bytes of `"collection"` followed by `collection` and `index` encoded as 8 big-endian bytes.
+///
+/// # Arguments
+///
+/// * `collection`: [b256] - The identifier of the collection.
+/// * `index`: [u64] - The index of the asset within the collection.
+///
+/// # Returns
+///
+/// * [SubId] - The sub-identifier derived from `collection` and `index`.
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::sub_id::{sub_id_from_collection, sub_id_from_string};
+/// use std::string::String;
+///
+/// fn foo() {
+///     let collection = sub_id_from_string(String::from_ascii_str("Fuel Punks"));
+///     let sub_id = sub_id_from_collection(collection, 42);
+/// }
+/// ```
+pub fn sub_id_from_collection(collection: b256, index: u64) -> SubId {
+    sha256(("collection", collection, index))
+}
+
+/// Returns the `AssetId` of an asset minted by this contract.
+///
+/// # Additional Information
+///
+/// The `AssetId` is the SHA-256 hash of this contract's `ContractId` followed by `sub_id`, the same as that of the assets minted by `_mint()`.
+///
+/// # Arguments
+///
+/// * `sub_id`: [SubId] - The sub-identifier of the asset.
+///
+/// # Returns
+///
+/// * [AssetId] - The `AssetId` of the asset with `sub_id` minted by this contract.
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::sub_id::{asset_id_from_sub_id, sub_id_from_serial};
+///
+/// fn foo() {
+///     let asset_id = asset_id_from_sub_id(sub_id_from_serial(1));
+///     assert(asset_id == AssetId::new(ContractId::this(), sub_id_from_serial(1)));
+/// }
+/// ```
+pub fn asset_id_from_sub_id(sub_id: SubId) -> AssetId {
+    AssetId::new(ContractId::this(), sub_id)
+}
@@ -5,10 +5,15 @@ use ::base::{_total_assets, _total_supply};
 use std::{
     asset::{
         burn,
+        mint,
         mint_to,
+        transfer,
     },
     call_frames::msg_asset_id,
-    context::this_balance,
+    context::{
+        msg_amount,
+        this_balance,
+    },
     hash::{
         Hash,
         sha256,
@@ -24,11 +29,14 @@ use src20::TotalSupplyEvent;
 ///
 /// **Warning** This function increases the total supply by the number of coins minted.
 /// **Note:** If `None` is passed for the `sub_id` argument, `b256::zero()` is used as the `SubId`.
+/// **Note:** If a maximum supply has been set for the asset with `_set_max_supply()`, it is enforced.
+/// **Note:** The new total supply is recorded as a checkpoint which may be queried with `_total_supply_at()`.
 ///
 /// # Arguments
 ///
 /// * `total_assets_key`: [StorageKey<u64>] - The location in storage that the `u64` which represents the total assets is stored.
 /// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `max_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the maximum supply of assets is stored.
 /// * `recipient`: [Identity] - The user to which the newly minted asset is transferred to.
 /// * `sub_id`: [SubId] - The sub-identifier of the newly minted asset.
 /// * `amount`: [u64] - The quantity of coins to mint.
@@ -40,11 +48,12 @@ use src20::TotalSupplyEvent;
 /// # Reverts
 ///
 /// * When `amount` is zero.
+/// * When minting `amount` would exceed the maximum supply of the asset.
 ///
 /// # Number of Storage Accesses
 ///
-/// * Reads: `2`
-/// * Writes: `2`
+/// * Reads: `5`
+/// * Writes: `5`
 ///
 /// # Examples
 ///
@@ -55,11 +64,12 @@ use src20::TotalSupplyEvent;
 /// storage {
 ///     total_assets: u64 = 0,
 ///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+///     max_supply: StorageMap<AssetId, u64> = StorageMap {},
 /// }
 ///
 /// fn foo(recipient: Identity) {
 ///     let recipient = Identity::ContractId(ContractId::zero());
-///     let asset_id = _mint(storage.total_assets, storage.total_supply, recipient, SubId::zero(), 100);
+///     let asset_id = _mint(storage.total_assets, storage.total_supply, storage.max_supply, recipient, SubId::zero(), 100);
 ///     assert(balance_of(recipient.as_contract_id(), asset_id), 100);
 /// }
 /// ```
@@ -67,6 +77,7 @@ use src20::TotalSupplyEvent;
 pub fn _mint(
     total_assets_key: StorageKey<u64>,
     total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    max_supply_key: StorageKey<StorageMap<AssetId, u64>>,
     recipient: Identity,
     sub_id: SubId,
     amount: u64,
@@ -75,14 +86,19 @@ pub fn _mint(
 
     let asset_id = AssetId::new(ContractId::this(), sub_id);
     let supply = _total_supply(total_supply_key, asset_id);
+    let current_supply = supply.unwrap_or(0);
+
+    require_within_max_supply(max_supply_key, asset_id, current_supply, amount);
 
     // Only increment the number of assets minted by this contract if it hasn't been minted before.
     if supply.is_none() {
-        total_assets_key.write(_total_assets(total_assets_key) + 1);
+        let total_assets = _total_assets(total_assets_key);
+        sub_ids_key(total_assets_key).insert(total_assets, sub_id);
+        total_assets_key.write(total_assets + 1);
     }
 
-    let current_supply = supply.unwrap_or(0);
     total_supply_key.insert(asset_id, current_supply + amount);
+    record_checkpoint(total_supply_key, asset_id, current_supply + amount);
 
     mint_to(recipient, sub_id, amount);
     log(TotalSupplyEvent {
@@ -114,8 +130,8 @@ pub fn _mint(
 ///
 /// # Number of Storage Accesses
 ///
-/// * Reads: `1`
-/// * Writes: `1`
+/// * Reads: `3`
+/// * Writes: `3`
 ///
 /// # Examples
 ///
@@ -147,6 +163,74 @@ pub fn _burn(
     // If we pass the check above, we can assume it is safe to unwrap.
     let supply = _total_supply(total_supply_key, asset_id).unwrap();
     total_supply_key.insert(asset_id, supply - amount);
+    record_checkpoint(total_supply_key, asset_id, supply - amount);
+
+    burn(sub_id, amount);
+    log(TotalSupplyEvent {
+        asset: asset_id,
+        supply: supply - amount,
+        sender: msg_sender().unwrap(),
+    });
+}
+
+/// Burns the coins forwarded with the call.
+///
+/// # Additional Information
+///
+/// Unlike `_burn()`, this function burns exactly the coins forwarded with the current call and cannot burn coins already held by the contract.
+/// The calling contract function must be `#[payable]`.
+/// **Warning** This function reduces the total supply by the number of coins burned. Using this value when minting may cause unintended consequences.
+///
+/// # Arguments
+///
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `sub_id`: [SubId] - The sub-identifier of the asset to burn.
+///
+/// # Returns
+///
+/// * [u64] - The quantity of coins burned.
+///
+/// # Reverts
+///
+/// * When no coins are forwarded.
+/// * When the forwarded coins are not of the asset with the given `sub_id`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `3`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::supply::_burn_forwarded;
+///
+/// storage {
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// #[payable]
+/// #[storage(read, write)]
+/// fn foo() {
+///     let burned = _burn_forwarded(storage.total_supply, SubId::zero());
+///     assert(burned == msg_amount());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _burn_forwarded(
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    sub_id: SubId,
+) -> u64 {
+    let amount = msg_amount();
+    require(amount > 0, BurnError::ZeroAmount);
+
+    let asset_id = AssetId::new(ContractId::this(), sub_id);
+    require(msg_asset_id() == asset_id, BurnError::IncorrectAsset);
+
+    // The forwarded coins could only have been minted by this contract, so a supply exists.
+    let supply = _total_supply(total_supply_key, asset_id).unwrap();
+    total_supply_key.insert(asset_id, supply - amount);
+    record_checkpoint(total_supply_key, asset_id, supply - amount);
 
     burn(sub_id, amount);
     log(TotalSupplyEvent {
@@ -154,4 +238,502 @@ pub fn _burn(
         supply: supply - amount,
         sender: msg_sender().unwrap(),
     });
+
+    amount
+}
+
+/// Mints new assets to many recipients at once.
+///
+/// # Additional Information
+///
+/// Amounts of the same asset are aggregated, so each total supply is written once and a single `TotalSupplyEvent` is logged per distinct asset.
+/// **Warning** This function increases the total supply by the number of coins minted.
+/// **Note:** If a maximum supply has been set for an asset with `_set_max_supply()`, it is enforced against the aggregated amount.
+///
+/// # Arguments
+///
+/// * `total_assets_key`: [StorageKey<u64>] - The location in storage that the `u64` which represents the total assets is stored.
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `max_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the maximum supply of assets is stored.
+/// * `recipients`: [Vec<(Identity, SubId, u64)>] - The recipients, the sub-identifiers of the assets to mint and the quantities of coins to mint.
+///
+/// # Reverts
+///
+/// * When any amount is zero.
+/// * When minting would exceed the maximum supply of any asset.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `4` per distinct asset + `1`
+/// * Writes: `4` per distinct asset + `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::{base::_total_supply, supply::_mint_batch};
+///
+/// storage {
+///     total_assets: u64 = 0,
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+///     max_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(recipient_1: Identity, recipient_2: Identity) {
+///     let mut recipients = Vec::new();
+///     recipients.push((recipient_1, SubId::zero(), 100));
+///     recipients.push((recipient_2, SubId::zero(), 200));
+///
+///     _mint_batch(storage.total_assets, storage.total_supply, storage.max_supply, recipients);
+///     let asset_id = AssetId::new(ContractId::this(), SubId::zero());
+///     assert(_total_supply(storage.total_supply, asset_id).unwrap() == 300);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _mint_batch(
+    total_assets_key: StorageKey<u64>,
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    max_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    recipients: Vec<(Identity, SubId, u64)>,
+) {
+    let mut sub_ids: Vec<SubId> = Vec::new();
+    let mut amounts: Vec<u64> = Vec::new();
+    let mut i = 0;
+    while i < recipients.len() {
+        let (_, sub_id, amount) = recipients.get(i).unwrap();
+        require(amount > 0, MintError::ZeroAmount);
+        aggregate(sub_ids, amounts, sub_id, amount);
+        i += 1;
+    }
+
+    let sender = msg_sender().unwrap();
+    let total_assets = _total_assets(total_assets_key);
+    let mut new_assets = 0;
+    let mut i = 0;
+    while i < sub_ids.len() {
+        let sub_id = sub_ids.get(i).unwrap();
+        let amount = amounts.get(i).unwrap();
+        let asset_id = AssetId::new(ContractId::this(), sub_id);
+        let supply = _total_supply(total_supply_key, asset_id);
+        let current_supply = supply.unwrap_or(0);
+
+        require_within_max_supply(max_supply_key, asset_id, current_supply, amount);
+
+        if supply.is_none() {
+            sub_ids_key(total_assets_key).insert(total_assets + new_assets, sub_id);
+            new_assets += 1;
+        }
+
+        total_supply_key.insert(asset_id, current_supply + amount);
+        record_checkpoint(total_supply_key, asset_id, current_supply + amount);
+
+        mint(sub_id, amount);
+        log(TotalSupplyEvent {
+            asset: asset_id,
+            supply: current_supply + amount,
+            sender,
+        });
+        i += 1;
+    }
+
+    // Only increment the number of assets minted by this contract for assets which haven't been minted before.
+    if new_assets > 0 {
+        total_assets_key.write(total_assets + new_assets);
+    }
+
+    let mut i = 0;
+    while i < recipients.len() {
+        let (recipient, sub_id, amount) = recipients.get(i).unwrap();
+        transfer(recipient, AssetId::new(ContractId::this(), sub_id), amount);
+        i += 1;
+    }
+}
+
+/// Burns many assets at once.
+///
+/// # Additional Information
+///
+/// Amounts of the same asset are aggregated, so each total supply is written once and a single `TotalSupplyEvent` is logged per distinct asset.
+/// **Warning** This function burns assets unequivocally. It does not check that assets are sent to the calling contract.
+/// **Warning** This function reduces the total supply by the number of coins burned. Using this value when minting may cause unintended consequences.
+///
+/// # Arguments
+///
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `burns`: [Vec<(SubId, u64)>] - The sub-identifiers of the assets to burn and the quantities of coins to burn.
+///
+/// # Reverts
+///
+/// * When any amount is zero.
+/// * When the calling contract does not have enough coins of any asset.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `3` per distinct asset
+/// * Writes: `3` per distinct asset
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::supply::_burn_batch;
+///
+/// storage {
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(sub_id_1: SubId, sub_id_2: SubId) {
+///     let mut burns = Vec::new();
+///     burns.push((sub_id_1, 100));
+///     burns.push((sub_id_2, 200));
+///
+///     _burn_batch(storage.total_supply, burns);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _burn_batch(
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    burns: Vec<(SubId, u64)>,
+) {
+    let mut sub_ids: Vec<SubId> = Vec::new();
+    let mut amounts: Vec<u64> = Vec::new();
+    let mut i = 0;
+    while i < burns.len() {
+        let (sub_id, amount) = burns.get(i).unwrap();
+        require(amount > 0, BurnError::ZeroAmount);
+        aggregate(sub_ids, amounts, sub_id, amount);
+        i += 1;
+    }
+
+    let sender = msg_sender().unwrap();
+    let mut i = 0;
+    while i < sub_ids.len() {
+        let sub_id = sub_ids.get(i).unwrap();
+        let amount = amounts.get(i).unwrap();
+        let asset_id = AssetId::new(ContractId::this(), sub_id);
+
+        require(this_balance(asset_id) >= amount, BurnError::NotEnoughCoins);
+
+        // If we pass the check above, we can assume it is safe to unwrap.
+        let supply = _total_supply(total_supply_key, asset_id).unwrap();
+        total_supply_key.insert(asset_id, supply - amount);
+        record_checkpoint(total_supply_key, asset_id, supply - amount);
+
+        burn(sub_id, amount);
+        log(TotalSupplyEvent {
+            asset: asset_id,
+            supply: supply - amount,
+            sender,
+        });
+        i += 1;
+    }
+}
+
+/// Returns the `AssetId` of the asset minted at the given index.
+///
+/// # Additional Information
+///
+/// Assets are indexed in the order in which they were first minted with `_mint()` or `_mint_batch()`, from `0` up to the total assets.
+///
+/// # Arguments
+///
+/// * `total_assets_key`: [StorageKey<u64>] - The location in storage that the `u64` which represents the total assets is stored.
+/// * `index`: [u64] - The index of the asset.
+///
+/// # Returns
+///
+/// * [Option<AssetId>] - The `AssetId` of the asset at `index`, or `None` if `index` is not less than the total assets.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::{base::_total_assets, supply::_asset_at};
+///
+/// storage {
+///     total_assets: u64 = 0,
+/// }
+///
+/// fn foo() {
+///     let mut index = 0;
+///     while index < _total_assets(storage.total_assets) {
+///         let asset_id = _asset_at(storage.total_assets, index).unwrap();
+///         index += 1;
+///     }
+/// }
+/// ```
+#[storage(read)]
+pub fn _asset_at(total_assets_key: StorageKey<u64>, index: u64) -> Option<AssetId> {
+    match _sub_id_at(total_assets_key, index) {
+        Some(sub_id) => Some(AssetId::new(ContractId::this(), sub_id)),
+        None => None,
+    }
+}
+
+/// Returns the sub-identifier of the asset minted at the given index.
+///
+/// # Additional Information
+///
+/// Assets are indexed in the order in which they were first minted with `_mint()` or `_mint_batch()`, from `0` up to the total assets.
+///
+/// # Arguments
+///
+/// * `total_assets_key`: [StorageKey<u64>] - The location in storage that the `u64` which represents the total assets is stored.
+/// * `index`: [u64] - The index of the asset.
+///
+/// # Returns
+///
+/// * [Option<SubId>] - The `SubId` of the asset at `index`, or `None` if `index` is not less than the total assets.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::supply::{_mint, _sub_id_at};
+///
+/// storage {
+///     total_assets: u64 = 0,
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+///     max_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(recipient: Identity) {
+///     let _ = _mint(storage.total_assets, storage.total_supply, storage.max_supply, recipient, SubId::zero(), 100);
+///     assert(_sub_id_at(storage.total_assets, 0).unwrap() == SubId::zero());
+/// }
+/// ```
+#[storage(read)]
+pub fn _sub_id_at(total_assets_key: StorageKey<u64>, index: u64) -> Option<SubId> {
+    sub_ids_key(total_assets_key).get(index).try_read()
+}
+
+/// Returns the maximum supply of an asset.
+///
+/// # Arguments
+///
+/// * `max_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the maximum supply of assets is stored.
+/// * `asset`: [AssetId] - The asset of which to query the maximum supply.
+///
+/// # Returns
+///
+/// * [Option<u64>] - The maximum supply of `asset`, or `None` if the supply is uncapped.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::supply::_max_supply;
+///
+/// storage {
+///     max_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     let max_supply = _max_supply(storage.max_supply, asset);
+///     assert(max_supply.is_none());
+/// }
+/// ```
+#[storage(read)]
+pub fn _max_supply(
+    max_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    asset: AssetId,
+) -> Option<u64> {
+    max_supply_key.get(asset).try_read()
+}
+
+/// Sets the maximum supply of an asset.
+///
+/// # Additional Information
+///
+/// **Warning** This function does not check the current supply. Setting a maximum supply below the current supply prevents any further minting of the asset.
+/// **Note:** The same `max_supply_key` must be passed to `_mint()` for the maximum supply to be enforced.
+///
+/// # Arguments
+///
+/// * `max_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the maximum supply of assets is stored.
+/// * `asset`: [AssetId] - The asset of which to set the maximum supply.
+/// * `max_supply`: [u64] - The maximum number of coins of `asset` which may be in circulation.
+///
+/// # Number of Storage Accesses
+///
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::supply::{_max_supply, _set_max_supply};
+///
+/// storage {
+///     max_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(asset: AssetId) {
+///     _set_max_supply(storage.max_supply, asset, 1_000_000);
+///     assert(_max_supply(storage.max_supply, asset).unwrap() == 1_000_000);
+/// }
+/// ```
+#[storage(write)]
+pub fn _set_max_supply(
+    max_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    asset: AssetId,
+    max_supply: u64,
+) {
+    max_supply_key.insert(asset, max_supply);
+}
+
+/// Returns the total supply of an asset at the end of a past block.
+///
+/// # Additional Information
+///
+/// Every change to the total supply made with the functions of this module is recorded as a checkpoint of the block height and the new supply.
+/// The checkpoints are searched with a binary search, so the number of storage reads grows logarithmically with the number of checkpoints.
+/// **Note:** The checkpoints are stored at a location derived from `total_supply_key`, so the same key must be used as when calling `_mint()`.
+///
+/// # Arguments
+///
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `asset`: [AssetId] - The asset of which to query the total supply.
+/// * `height`: [u32] - The block height at the end of which to query the total supply.
+///
+/// # Returns
+///
+/// * [Option<u64>] - The total supply of `asset` at the end of block `height`, or `None` if the asset had not been minted by then.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1` + `log2(n)` where `n` is the number of checkpoints of `asset`
+///
+/// # Examples
+///
+/// ```sway
+/// use asset::supply::{_mint, _total_supply_at};
+/// use std::block::height;
+///
+/// storage {
+///     total_assets: u64 = 0,
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+///     max_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(recipient: Identity) {
+///     let asset_id = _mint(storage.total_assets, storage.total_supply, storage.max_supply, recipient, SubId::zero(), 100);
+///     assert(_total_supply_at(storage.total_supply, asset_id, height()).unwrap() == 100);
+///     assert(_total_supply_at(storage.total_supply, asset_id, height() - 1).is_none());
+/// }
+/// ```
+#[storage(read)]
+pub fn _total_supply_at(
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    asset: AssetId,
+    height: u32,
+) -> Option<u64> {
+    let checkpoints = checkpoints_key(total_supply_key);
+    let len = checkpoints_len_key(total_supply_key).get(asset).try_read().unwrap_or(0);
+
+    // Find the number of checkpoints recorded at or before `height`.
+    let mut low = 0;
+    let mut high = len;
+    while low < high {
+        let mid = (low + high) / 2;
+        let (checkpoint_height, _) = checkpoints.get((asset, mid)).read();
+        if checkpoint_height <= height {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low == 0 {
+        return None;
+    }
+
+    let (_, supply) = checkpoints.get((asset, low - 1)).read();
+    Some(supply)
+}
+
+fn sub_ids_key(total_assets_key: StorageKey<u64>) -> StorageKey<StorageMap<u64, SubId>> {
+    let key_digest = sha256(("sub_ids", total_assets_key.field_id()));
+    StorageKey::new(key_digest, 0, key_digest)
+}
+
+fn checkpoints_key(
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+) -> StorageKey<StorageMap<(AssetId, u64), (u32, u64)>> {
+    let key_digest = sha256(("supply_checkpoints", total_supply_key.field_id()));
+    StorageKey::new(key_digest, 0, key_digest)
+}
+
+fn checkpoints_len_key(
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+) -> StorageKey<StorageMap<AssetId, u64>> {
+    let key_digest = sha256(("supply_checkpoints_len", total_supply_key.field_id()));
+    StorageKey::new(key_digest, 0, key_digest)
+}
+
+#[storage(read, write)]
+fn record_checkpoint(
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    asset_id: AssetId,
+    supply: u64,
+) {
+    let checkpoints = checkpoints_key(total_supply_key);
+    let len_key = checkpoints_len_key(total_supply_key);
+    let len = len_key.get(asset_id).try_read().unwrap_or(0);
+    let current_height = std::block::height();
+
+    // Only keep the latest supply of a block so that each height has at most one checkpoint.
+    if len > 0 {
+        let (last_height, _) = checkpoints.get((asset_id, len - 1)).read();
+        if last_height == current_height {
+            checkpoints.insert((asset_id, len - 1), (current_height, supply));
+            return;
+        }
+    }
+
+    checkpoints.insert((asset_id, len), (current_height, supply));
+    len_key.insert(asset_id, len + 1);
+}
+
+#[storage(read)]
+fn require_within_max_supply(
+    max_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    asset_id: AssetId,
+    current_supply: u64,
+    amount: u64,
+) {
+    match _max_supply(max_supply_key, asset_id) {
+        Some(max_supply) => {
+            require(
+                current_supply <= max_supply && amount <= max_supply - current_supply,
+                MintError::MaxSupplyExceeded,
+            );
+        },
+        None => (),
+    }
+}
+
+fn aggregate(
+    ref mut sub_ids: Vec<SubId>,
+    ref mut amounts: Vec<u64>,
+    sub_id: SubId,
+    amount: u64,
+) {
+    let mut i = 0;
+    while i < sub_ids.len() {
+        if sub_ids.get(i).unwrap() == sub_id {
+            amounts.set(i, amounts.get(i).unwrap() + amount);
+            return;
+        }
+        i += 1;
+    }
+
+    sub_ids.push(sub_id);
+    amounts.push(amount);
 }
@@ -0,0 +1,151 @@
+library;
+
+use asset::supply::{_burn, _mint};
+use admin::{only_owner_or_admin, roles::only_role};
+use ownership::only_owner;
+use pausable::require_not_paused;
+use std::hash::Hash;
+
+/// The authorization policy which the caller must satisfy to mint or burn.
+pub enum AccessPolicy {
+    /// Only the contract owner set with the Ownership Library may mint or burn.
+    Owner: (),
+    /// The contract owner or any administrator added with the Admin Library may mint or burn.
+    Admin: (),
+    /// Only holders of the role may mint or burn.
+    Role: b256,
+}
+
+/// Mints new assets using the `sub_id` sub-identifier when the contract is unpaused and the caller satisfies `policy`.
+///
+/// # Additional Information
+///
+/// This function wraps `_mint()`, so the total supply, maximum supply, and checkpoints are handled in the same way.
+/// **Note:** The pause state is checked before the authorization policy.
+///
+/// # Arguments
+///
+/// * `policy`: [AccessPolicy] - The authorization policy which the caller must satisfy.
+/// * `total_assets_key`: [StorageKey<u64>] - The location in storage that the `u64` which represents the total assets is stored.
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `max_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the maximum supply of assets is stored.
+/// * `recipient`: [Identity] - The user to which the newly minted asset is transferred to.
+/// * `sub_id`: [SubId] - The sub-identifier of the newly minted asset.
+/// * `amount`: [u64] - The quantity of coins to mint.
+///
+/// # Returns
+///
+/// * [AssetId] - The `AssetId` of the newly minted asset.
+///
+/// # Reverts
+///
+/// * When the contract is paused.
+/// * When the caller does not satisfy `policy`.
+/// * When `_mint()` reverts.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `7` for `AccessPolicy::Owner` and `AccessPolicy::Role`, `8` for `AccessPolicy::Admin`
+/// * Writes: `5`
+///
+/// # Examples
+///
+/// ```sway
+/// use guarded_asset::{_guarded_mint, AccessPolicy};
+///
+/// storage {
+///     total_assets: u64 = 0,
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+///     max_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo(recipient: Identity) {
+///     let asset_id = _guarded_mint(AccessPolicy::Owner, storage.total_assets, storage.total_supply, storage.max_supply, recipient, SubId::zero(), 100);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _guarded_mint(
+    policy: AccessPolicy,
+    total_assets_key: StorageKey<u64>,
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    max_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    recipient: Identity,
+    sub_id: SubId,
+    amount: u64,
+) -> AssetId {
+    require_not_paused();
+    require_policy(policy);
+
+    _mint(
+        total_assets_key,
+        total_supply_key,
+        max_supply_key,
+        recipient,
+        sub_id,
+        amount,
+    )
+}
+
+/// Burns assets with the given `sub_id` when the contract is unpaused and the caller satisfies `policy`.
+///
+/// # Additional Information
+///
+/// This function wraps `_burn()`, so the total supply and checkpoints are handled in the same way.
+/// **Warning** This function burns assets unequivocally. It does not check that assets are sent to the calling contract.
+/// **Note:** The pause state is checked before the authorization policy.
+///
+/// # Arguments
+///
+/// * `policy`: [AccessPolicy] - The authorization policy which the caller must satisfy.
+/// * `total_supply_key`: [StorageKey<StorageMap<AssetId, u64>>] - The location in storage which the `StorageMap` that stores the total supply of assets is stored.
+/// * `sub_id`: [SubId] - The sub-identifier of the asset to burn.
+/// * `amount`: [u64] - The quantity of coins to burn.
+///
+/// # Reverts
+///
+/// * When the contract is paused.
+/// * When the caller does not satisfy `policy`.
+/// * When `_burn()` reverts.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `5` for `AccessPolicy::Owner` and `AccessPolicy::Role`, `6` for `AccessPolicy::Admin`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use guarded_asset::{_guarded_burn, AccessPolicy};
+///
+/// // Pre-computed hash digest of sha256("burner")
+/// const BURNER_ROLE: b256 = 0x67b8ea9ae3c31ecb78013c925ff237dd1a7e72845a7f8c99280be25258c0d105;
+///
+/// storage {
+///     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+/// }
+///
+/// fn foo() {
+///     _guarded_burn(AccessPolicy::Role(BURNER_ROLE), storage.total_supply, SubId::zero(), 100);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _guarded_burn(
+    policy: AccessPolicy,
+    total_supply_key: StorageKey<StorageMap<AssetId, u64>>,
+    sub_id: SubId,
+    amount: u64,
+) {
+    require_not_paused();
+    require_policy(policy);
+
+    _burn(total_supply_key, sub_id, amount);
+}
+
+#[storage(read)]
+fn require_policy(policy: AccessPolicy) {
+    match policy {
+        AccessPolicy::Owner => only_owner(),
+        AccessPolicy::Admin => only_owner_or_admin(),
+        AccessPolicy::Role(role) => only_role(role),
+    }
+}
@@ -5,3 +5,29 @@ pub enum InitializationError {
     /// Emitted when an owner has already been set.
     CannotReinitialized: (),
 }
+
+/// Error log for when a pending ownership transfer cannot be completed or cancelled.
+pub enum PendingOwnershipError {
+    /// Emitted when the caller is not the pending owner.
+    NotPendingOwner: (),
+    /// Emitted when there is no pending owner.
+    NoPendingOwner: (),
+    /// Emitted when a pending owner accepts ownership which is not initialized.
+    OwnerNotInitialized: (),
+}
+
+/// Error log for when a scheduled ownership transfer cannot be executed or cancelled.
+pub enum OwnershipTimelockError {
+    /// Emitted when there is no scheduled ownership transfer.
+    NotScheduled: (),
+    /// Emitted when the delay of the scheduled ownership transfer has not yet elapsed.
+    NotElapsed: (),
+    /// Emitted when the minimum delay has not been set.
+    DelayNotInitialized: (),
+    /// Emitted when the minimum delay has already been set.
+    DelayAlreadyInitialized: (),
+    /// Emitted when the delay is zero or less than the minimum delay.
+    DelayTooShort: (),
+    /// Emitted when the delay would overflow the block timestamp.
+    DelayOverflow: (),
+}
@@ -1,5 +1,13 @@
 library;
 
+/// Logged when the minimum delay of scheduled ownership transfers is set.
+pub struct OwnershipDelaySet {
+    /// The minimum number of seconds which must elapse before a scheduled transfer may be executed.
+    pub min_delay: u64,
+    /// The user which is currently the owner.
+    pub owner: Identity,
+}
+
 /// Logged when ownership is renounced.
 pub struct OwnershipRenounced {
     /// The user which revoked the ownership.
@@ -12,6 +20,22 @@ pub struct OwnershipSet {
     pub new_owner: Identity,
 }
 
+/// Logged when a proposed transfer of ownership is cancelled.
+pub struct OwnershipTransferCancelled {
+    /// The user which may no longer accept the ownership.
+    pub pending_owner: Identity,
+    /// The user which is currently the owner.
+    pub owner: Identity,
+}
+
+/// Logged when a transfer of ownership to a user is proposed.
+pub struct OwnershipTransferProposed {
+    /// The user which may accept the ownership.
+    pub pending_owner: Identity,
+    /// The user which is currently the owner.
+    pub owner: Identity,
+}
+
 /// Logged when ownership is given from one user to another.
 pub struct OwnershipTransferred {
     /// The user which is now the owner.
@@ -19,3 +43,29 @@ pub struct OwnershipTransferred {
     /// The user which has given up their ownership.
     pub previous_owner: Identity,
 }
+
+/// Logged when a transfer of ownership is scheduled.
+pub struct OwnershipTransferScheduled {
+    /// The user which will become the owner.
+    pub new_owner: Identity,
+    /// The earliest timestamp at which the transfer may be executed.
+    pub eta: u64,
+    /// The user which is currently the owner.
+    pub owner: Identity,
+}
+
+/// Logged when a scheduled transfer of ownership is cancelled.
+pub struct ScheduledOwnershipTransferCancelled {
+    /// The user which would have become the owner.
+    pub new_owner: Identity,
+    /// The user which is currently the owner.
+    pub owner: Identity,
+}
+
+/// Logged when a scheduled transfer of ownership is executed.
+pub struct ScheduledOwnershipTransferExecuted {
+    /// The user which is now the owner.
+    pub new_owner: Identity,
+    /// The user which has given up their ownership.
+    pub previous_owner: Identity,
+}
@@ -2,14 +2,25 @@ library;
 
 pub mod errors;
 pub mod events;
+pub mod timelock;
 
-use ::errors::InitializationError;
-use ::events::{OwnershipRenounced, OwnershipSet, OwnershipTransferred};
-use std::{auth::msg_sender, hash::sha256, storage::storage_api::{read, write}};
+use ::errors::{InitializationError, PendingOwnershipError};
+use ::events::{
+    OwnershipRenounced,
+    OwnershipSet,
+    OwnershipTransferCancelled,
+    OwnershipTransferProposed,
+    OwnershipTransferred,
+};
+use std::{auth::msg_sender, hash::sha256, storage::storage_api::{clear, read, write}};
 use src5::{AccessError, State};
 
 // Pre-computed hash digest of sha256("owner")
 const OWNER = 0x4c1029697ee358715d3a14a2add817c4b01651440de808371f78165ac90dc581;
+// Pre-computed hash digest of sha256("pending_owner")
+const PENDING_OWNER = 0xcd70f167d6ff5bdd9495bb5ec9ebb9999cbf25d9b513fec448b87174b569a1e9;
+// Pre-computed hash digest of sha256("scheduled_owner")
+const SCHEDULED_OWNER = 0xf7506bc464040e5104df944be69a1872dffd402d6737cd1cdb88b94c99ef4aba;
 
 /// Returns the owner.
 ///
@@ -66,6 +77,10 @@ pub fn only_owner() {
 
 /// Revokes ownership of the current owner and disallows any new owners.
 ///
+/// # Additional Information
+///
+/// Any pending or scheduled ownership transfer is cancelled.
+///
 /// # Reverts
 ///
 /// * When the sender is not the owner.
@@ -73,7 +88,7 @@ pub fn only_owner() {
 /// # Number of Storage Accesses
 ///
 /// * Reads: `1`
-/// * Writes: `1`
+/// * Writes: `3`
 ///
 /// # Examples
 ///
@@ -93,6 +108,16 @@ pub fn renounce_ownership() {
     let owner_key = StorageKey::new(OWNER, 0, OWNER);
     owner_key.write(State::Revoked);
 
+    let pending_owner_key = StorageKey::<Identity>::new(PENDING_OWNER, 0, PENDING_OWNER);
+    let _ = pending_owner_key.clear();
+
+    let scheduled_owner_key = StorageKey::<(Identity, u64)>::new(
+        SCHEDULED_OWNER,
+        0,
+        SCHEDULED_OWNER,
+    );
+    let _ = scheduled_owner_key.clear();
+
     log(OwnershipRenounced {
         previous_owner: msg_sender().unwrap(),
     });
@@ -139,6 +164,10 @@ pub fn initialize_ownership(new_owner: Identity) {
 
 /// Transfers ownership to the passed identity.
 ///
+/// # Additional Information
+///
+/// Ownership is transferred immediately and any pending or scheduled ownership transfer is cancelled. To require the new owner to accept ownership, use `propose_ownership_transfer()` instead.
+///
 /// # Arguments
 ///
 /// * `new_owner`: [Identity] - The `Identity` that will be the next owner.
@@ -150,7 +179,7 @@ pub fn initialize_ownership(new_owner: Identity) {
 /// # Number of Storage Acesses
 ///
 /// * Reads: `1`
-/// * Write: `1`
+/// * Write: `3`
 ///
 /// # Examples
 ///
@@ -170,8 +199,188 @@ pub fn transfer_ownership(new_owner: Identity) {
     let owner_key = StorageKey::new(OWNER, 0, OWNER);
     owner_key.write(State::Initialized(new_owner));
 
+    let pending_owner_key = StorageKey::<Identity>::new(PENDING_OWNER, 0, PENDING_OWNER);
+    let _ = pending_owner_key.clear();
+
+    let scheduled_owner_key = StorageKey::<(Identity, u64)>::new(
+        SCHEDULED_OWNER,
+        0,
+        SCHEDULED_OWNER,
+    );
+    let _ = scheduled_owner_key.clear();
+
     log(OwnershipTransferred {
         new_owner,
         previous_owner: msg_sender().unwrap(),
     });
 }
+
+/// Returns the pending owner.
+///
+/// # Returns
+///
+/// * [Option<Identity>] - The `Identity` which may accept ownership, or `None` if there is no pending ownership transfer.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::_pending_owner;
+///
+/// fn foo() {
+///     let pending_owner = _pending_owner();
+/// }
+/// ```
+#[storage(read)]
+pub fn _pending_owner() -> Option<Identity> {
+    let pending_owner_key = StorageKey::<Identity>::new(PENDING_OWNER, 0, PENDING_OWNER);
+    pending_owner_key.try_read()
+}
+
+/// Proposes a transfer of ownership to the passed identity.
+///
+/// # Additional Information
+///
+/// Ownership does not change until `new_owner` calls `accept_ownership()`. Proposing a new owner replaces any previous proposal.
+///
+/// # Arguments
+///
+/// * `new_owner`: [Identity] - The `Identity` that may accept ownership.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+///
+/// # Number of Storage Acesses
+///
+/// * Reads: `1`
+/// * Write: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::{_owner, _pending_owner, propose_ownership_transfer};
+///
+/// fn foo(new_owner: Identity) {
+///     propose_ownership_transfer(new_owner);
+///     assert(_owner() == State::Initialized(Identity::Address(Address::zero())));
+///     assert(_pending_owner() == Some(new_owner));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn propose_ownership_transfer(new_owner: Identity) {
+    only_owner();
+
+    let pending_owner_key = StorageKey::<Identity>::new(PENDING_OWNER, 0, PENDING_OWNER);
+    pending_owner_key.write(new_owner);
+
+    log(OwnershipTransferProposed {
+        pending_owner: new_owner,
+        owner: msg_sender().unwrap(),
+    });
+}
+
+/// Accepts a proposed transfer of ownership, making the sender the owner.
+///
+/// # Additional Information
+///
+/// Any scheduled ownership transfer is cancelled.
+///
+/// # Reverts
+///
+/// * When the sender is not the pending owner.
+/// * When ownership is not initialized.
+///
+/// # Number of Storage Acesses
+///
+/// * Reads: `2`
+/// * Write: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::{_owner, _pending_owner, accept_ownership};
+///
+/// fn foo() {
+///     let new_owner = msg_sender().unwrap();
+///     assert(_pending_owner() == Some(new_owner));
+///     accept_ownership();
+///     assert(_owner() == State::Initialized(new_owner));
+///     assert(_pending_owner() == None);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn accept_ownership() {
+    let sender = msg_sender().unwrap();
+    require(
+        _pending_owner() == Some(sender),
+        PendingOwnershipError::NotPendingOwner,
+    );
+
+    // A pending owner may only be proposed while ownership is initialized.
+    let previous_owner = match _owner() {
+        State::Initialized(owner) => Some(owner),
+        _ => None,
+    };
+    require(previous_owner.is_some(), PendingOwnershipError::OwnerNotInitialized);
+
+    let owner_key = StorageKey::new(OWNER, 0, OWNER);
+    owner_key.write(State::Initialized(sender));
+
+    let pending_owner_key = StorageKey::<Identity>::new(PENDING_OWNER, 0, PENDING_OWNER);
+    let _ = pending_owner_key.clear();
+
+    let scheduled_owner_key = StorageKey::<(Identity, u64)>::new(
+        SCHEDULED_OWNER,
+        0,
+        SCHEDULED_OWNER,
+    );
+    let _ = scheduled_owner_key.clear();
+
+    log(OwnershipTransferred {
+        new_owner: sender,
+        previous_owner: previous_owner.unwrap(),
+    });
+}
+
+/// Cancels a proposed transfer of ownership.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+/// * When there is no pending owner.
+///
+/// # Number of Storage Acesses
+///
+/// * Reads: `2`
+/// * Write: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::{_pending_owner, cancel_ownership_transfer, propose_ownership_transfer};
+///
+/// fn foo(new_owner: Identity) {
+///     propose_ownership_transfer(new_owner);
+///     cancel_ownership_transfer();
+///     assert(_pending_owner() == None);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn cancel_ownership_transfer() {
+    only_owner();
+
+    let pending_owner = _pending_owner();
+    require(pending_owner.is_some(), PendingOwnershipError::NoPendingOwner);
+
+    let pending_owner_key = StorageKey::<Identity>::new(PENDING_OWNER, 0, PENDING_OWNER);
+    let _ = pending_owner_key.clear();
+
+    log(OwnershipTransferCancelled {
+        pending_owner: pending_owner.unwrap(),
+        owner: msg_sender().unwrap(),
+    });
+}
@@ -0,0 +1,254 @@
+library;
+
+use ::{only_owner, SCHEDULED_OWNER, transfer_ownership};
+use ::errors::OwnershipTimelockError;
+use ::events::{
+    OwnershipDelaySet,
+    OwnershipTransferScheduled,
+    ScheduledOwnershipTransferCancelled,
+    ScheduledOwnershipTransferExecuted,
+};
+use std::{auth::msg_sender, block::timestamp, storage::storage_api::clear};
+
+// Pre-computed hash digest of sha256("ownership_delay")
+const OWNERSHIP_DELAY = 0x9cdd4107d336fdc85060a5e7412c7542dcf47b3359d34c3bd6c7fa85256de9dd;
+
+/// Returns the minimum delay of scheduled ownership transfers.
+///
+/// # Returns
+///
+/// * [Option<u64>] - The minimum number of seconds which must elapse before a scheduled transfer may be executed, or `None` if the minimum delay has not been initialized.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::timelock::_ownership_delay;
+///
+/// fn foo() {
+///     let min_delay = _ownership_delay();
+/// }
+/// ```
+#[storage(read)]
+pub fn _ownership_delay() -> Option<u64> {
+    ownership_delay_key().try_read()
+}
+
+/// Sets the minimum delay of scheduled ownership transfers.
+///
+/// # Additional Information
+///
+/// The minimum delay may only be set once. Ownership transfers cannot be scheduled until it has been set.
+///
+/// # Arguments
+///
+/// * `min_delay`: [u64] - The minimum number of seconds which must elapse before a scheduled transfer may be executed.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+/// * When the minimum delay has already been set.
+/// * When `min_delay` is zero.
+///
+/// # Number of Storage Acesses
+///
+/// * Reads: `2`
+/// * Write: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::timelock::{_ownership_delay, initialize_ownership_delay};
+///
+/// fn foo() {
+///     initialize_ownership_delay(86400);
+///     assert(_ownership_delay() == Some(86400));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn initialize_ownership_delay(min_delay: u64) {
+    only_owner();
+
+    require(
+        _ownership_delay().is_none(),
+        OwnershipTimelockError::DelayAlreadyInitialized,
+    );
+    require(min_delay > 0, OwnershipTimelockError::DelayTooShort);
+
+    ownership_delay_key().write(min_delay);
+
+    log(OwnershipDelaySet {
+        min_delay,
+        owner: msg_sender().unwrap(),
+    });
+}
+
+/// Returns the scheduled ownership transfer.
+///
+/// # Returns
+///
+/// * [Option<(Identity, u64)>] - The `Identity` which will become the owner and the earliest timestamp at which the transfer may be executed, or `None` if no transfer is scheduled.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::timelock::_scheduled_ownership_transfer;
+///
+/// fn foo() {
+///     let scheduled = _scheduled_ownership_transfer();
+/// }
+/// ```
+#[storage(read)]
+pub fn _scheduled_ownership_transfer() -> Option<(Identity, u64)> {
+    scheduled_owner_key().try_read()
+}
+
+/// Schedules a transfer of ownership to the passed identity after a delay.
+///
+/// # Additional Information
+///
+/// The transfer may be executed with `execute_ownership_transfer()` once the block timestamp has reached the current block timestamp plus `delay`. Scheduling a new transfer replaces any previously scheduled transfer.
+///
+/// The minimum delay must first be set with `initialize_ownership_delay()`.
+///
+/// # Arguments
+///
+/// * `new_owner`: [Identity] - The `Identity` that will be the next owner.
+/// * `delay`: [u64] - The number of seconds which must elapse before the transfer may be executed.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+/// * When the minimum delay has not been set.
+/// * When `delay` is less than the minimum delay.
+/// * When the current block timestamp plus `delay` overflows.
+///
+/// # Number of Storage Acesses
+///
+/// * Reads: `2`
+/// * Write: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::timelock::{_scheduled_ownership_transfer, schedule_ownership_transfer};
+///
+/// fn foo(new_owner: Identity) {
+///     schedule_ownership_transfer(new_owner, 86400);
+///     assert(_scheduled_ownership_transfer().is_some());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn schedule_ownership_transfer(new_owner: Identity, delay: u64) {
+    only_owner();
+
+    let min_delay = _ownership_delay();
+    require(min_delay.is_some(), OwnershipTimelockError::DelayNotInitialized);
+    require(delay >= min_delay.unwrap(), OwnershipTimelockError::DelayTooShort);
+
+    let now = timestamp();
+    require(delay <= u64::max() - now, OwnershipTimelockError::DelayOverflow);
+
+    let eta = now + delay;
+    scheduled_owner_key().write((new_owner, eta));
+
+    log(OwnershipTransferScheduled {
+        new_owner,
+        eta,
+        owner: msg_sender().unwrap(),
+    });
+}
+
+/// Executes a scheduled transfer of ownership.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+/// * When there is no scheduled ownership transfer.
+/// * When the delay of the scheduled ownership transfer has not elapsed.
+///
+/// # Number of Storage Acesses
+///
+/// * Reads: `3`
+/// * Write: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::{_owner, timelock::execute_ownership_transfer};
+///
+/// fn foo(new_owner: Identity) {
+///     execute_ownership_transfer();
+///     assert(_owner() == State::Initialized(new_owner));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn execute_ownership_transfer() {
+    only_owner();
+
+    let scheduled = _scheduled_ownership_transfer();
+    require(scheduled.is_some(), OwnershipTimelockError::NotScheduled);
+
+    let (new_owner, eta) = scheduled.unwrap();
+    require(timestamp() >= eta, OwnershipTimelockError::NotElapsed);
+
+    // Clears the scheduled ownership transfer.
+    transfer_ownership(new_owner);
+
+    log(ScheduledOwnershipTransferExecuted {
+        new_owner,
+        previous_owner: msg_sender().unwrap(),
+    });
+}
+
+/// Cancels a scheduled transfer of ownership.
+///
+/// # Reverts
+///
+/// * When the sender is not the owner.
+/// * When there is no scheduled ownership transfer.
+///
+/// # Number of Storage Acesses
+///
+/// * Reads: `2`
+/// * Write: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use ownership::timelock::{_scheduled_ownership_transfer, cancel_scheduled_ownership_transfer};
+///
+/// fn foo() {
+///     cancel_scheduled_ownership_transfer();
+///     assert(_scheduled_ownership_transfer().is_none());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn cancel_scheduled_ownership_transfer() {
+    only_owner();
+
+    let scheduled = _scheduled_ownership_transfer();
+    require(scheduled.is_some(), OwnershipTimelockError::NotScheduled);
+
+    let _ = scheduled_owner_key().clear();
+
+    log(ScheduledOwnershipTransferCancelled {
+        new_owner: scheduled.unwrap().0,
+        owner: msg_sender().unwrap(),
+    });
+}
+
+fn scheduled_owner_key() -> StorageKey<(Identity, u64)> {
+    StorageKey::new(SCHEDULED_OWNER, 0, SCHEDULED_OWNER)
+}
+
+fn ownership_delay_key() -> StorageKey<u64> {
+    StorageKey::new(OWNERSHIP_DELAY, 0, OWNERSHIP_DELAY)
+}
@@ -1,7 +1,51 @@
 library;
 
+/// Error log for when the proxy owner has already been initialized.
+pub enum InitializationError {
+    /// Emitted when a proxy owner has already been set.
+    CannotReinitialized: (),
+}
+
 /// Error log for when setting proxy owner is denied.
 pub enum SetProxyOwnerError {
     /// Emitted when the owner state is being uninitialized.
     CannotUninitialize: (),
 }
+
+/// Error log for when a proposed proxy owner cannot be accepted.
+pub enum PendingProxyOwnerError {
+    /// Emitted when the sender is not the pending proxy owner.
+    NotPendingProxyOwner: (),
+}
+
+/// Error log for when a scheduled proxy change cannot be executed or cancelled.
+pub enum ProxyTimelockError {
+    /// Emitted when there is no scheduled change.
+    NotScheduled: (),
+    /// Emitted when the delay of the scheduled change has not yet elapsed.
+    NotElapsed: (),
+    /// Emitted when the scheduled timestamp is before the current block timestamp.
+    InvalidEta: (),
+    /// Emitted when the minimum delay has not been set.
+    DelayNotInitialized: (),
+    /// Emitted when the minimum delay has already been set.
+    DelayAlreadyInitialized: (),
+    /// Emitted when the delay is zero or less than the minimum delay.
+    DelayTooShort: (),
+    /// Emitted when the delay would overflow the block timestamp.
+    DelayOverflow: (),
+}
+
+/// Error log for when a proxy target fails verification.
+pub enum ProxyTargetVerificationError {
+    /// Emitted when the expected bytecode root has not been approved.
+    RootNotApproved: (),
+    /// Emitted when the bytecode root of the target does not match the expected root.
+    RootMismatch: (),
+}
+
+/// Error log for when the proxy target history cannot be used.
+pub enum ProxyTargetHistoryError {
+    /// Emitted when there is no previous target to roll back to.
+    NoPreviousTarget: (),
+}
@@ -8,8 +8,88 @@ pub struct ProxyTargetSet {
     pub new_target: ContractId,
 }
 
+/// Logged when the proxy target is rolled back to a previous target.
+pub struct ProxyTargetRolledBack {
+    /// The target contract which was replaced.
+    pub previous_target: ContractId,
+    /// The restored target contract.
+    pub new_target: ContractId,
+}
+
 /// Logged when ownership is a new proxy owner is set.
 pub struct ProxyOwnerSet {
     /// The new ownership state.
     pub new_proxy_owner: State,
 }
+
+/// Logged when a new proxy owner is proposed.
+pub struct ProxyOwnerProposed {
+    /// The user which may accept the proxy ownership.
+    pub pending_proxy_owner: Identity,
+    /// The user which is currently the proxy owner.
+    pub proxy_owner: Identity,
+}
+
+/// Logged when the proxy ownership is renounced.
+pub struct ProxyOwnerRenounced {
+    /// The user which revoked the proxy ownership.
+    pub previous_proxy_owner: Identity,
+}
+
+/// Logged when the minimum delay of scheduled proxy changes is set.
+pub struct ProxyDelaySet {
+    /// The minimum number of seconds which must elapse before a scheduled change may be executed.
+    pub min_delay: u64,
+}
+
+/// Logged when a new proxy owner is scheduled.
+pub struct ProxyOwnerScheduled {
+    /// The ownership state which will be set.
+    pub new_proxy_owner: State,
+    /// The earliest timestamp at which the change may be executed.
+    pub eta: u64,
+}
+
+/// Logged when a scheduled proxy owner is cancelled.
+pub struct ScheduledProxyOwnerCancelled {
+    /// The ownership state which would have been set.
+    pub new_proxy_owner: State,
+}
+
+/// Logged when a scheduled proxy owner is executed.
+pub struct ScheduledProxyOwnerExecuted {
+    /// The new ownership state.
+    pub new_proxy_owner: State,
+}
+
+/// Logged when a new proxy target is scheduled.
+pub struct ProxyTargetScheduled {
+    /// The target contract which will be set.
+    pub new_target: ContractId,
+    /// The earliest timestamp at which the upgrade may be executed.
+    pub eta: u64,
+}
+
+/// Logged when a scheduled proxy target is cancelled.
+pub struct ScheduledProxyTargetCancelled {
+    /// The target contract which would have been set.
+    pub new_target: ContractId,
+}
+
+/// Logged when a scheduled proxy target is executed.
+pub struct ScheduledProxyTargetExecuted {
+    /// The new target contract.
+    pub new_target: ContractId,
+}
+
+/// Logged when an implementation bytecode root is approved.
+pub struct ImplementationRootApproved {
+    /// The approved bytecode root.
+    pub root: b256,
+}
+
+/// Logged when an implementation bytecode root is revoked.
+pub struct ImplementationRootRevoked {
+    /// The revoked bytecode root.
+    pub root: b256,
+}
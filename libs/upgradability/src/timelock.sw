@@ -0,0 +1,444 @@
+library;
+
+use ::{_set_proxy_owner, _set_proxy_target, only_proxy_owner};
+use ::errors::{ProxyTimelockError, SetProxyOwnerError};
+use ::events::{
+    ProxyDelaySet,
+    ProxyOwnerScheduled,
+    ProxyTargetScheduled,
+    ScheduledProxyOwnerCancelled,
+    ScheduledProxyOwnerExecuted,
+    ScheduledProxyTargetCancelled,
+    ScheduledProxyTargetExecuted,
+};
+use std::{block::timestamp, storage::storage_api::clear};
+use src5::State;
+
+/// The storage slot to store the scheduled proxy owner State and its earliest execution timestamp.
+///
+/// Value is `sha256("storage_SRC14_scheduled_proxy_owner")`.
+pub const SCHEDULED_PROXY_OWNER_STORAGE: b256 = 0xd1f5eeaab00833cb70b5f3d0858130b50245eecdc46fc470c8bf638221ca339c;
+
+/// The storage slot to store the pending proxy target and its earliest execution timestamp.
+///
+/// Value is `sha256("storage_SRC14_pending_proxy_target")`.
+pub const PENDING_PROXY_TARGET_STORAGE: b256 = 0x4acd112278b4153635f27a941a4e127579e3803b24b2f5fa4091a760cf3554d6;
+
+/// The storage slot to store the minimum delay of scheduled proxy changes.
+///
+/// Value is `sha256("storage_SRC14_proxy_delay")`.
+pub const PROXY_DELAY_STORAGE: b256 = 0xeb3ca7dd55d5a05c4ecf3b4272b271d69b729690b7d7e28cb0cfba5d6778a21d;
+
+/// Returns the minimum delay of scheduled proxy changes.
+///
+/// # Returns
+///
+/// * [Option<u64>] - The minimum number of seconds which must elapse before a scheduled change may be executed, or `None` if the minimum delay has not been initialized.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::timelock::_proxy_delay;
+///
+/// fn foo() {
+///     let min_delay = _proxy_delay();
+/// }
+/// ```
+#[storage(read)]
+pub fn _proxy_delay() -> Option<u64> {
+    proxy_delay_key().try_read()
+}
+
+/// Sets the minimum delay of scheduled proxy changes.
+///
+/// # Additional Information
+///
+/// The minimum delay may only be set once. Proxy changes cannot be scheduled until it has been set.
+///
+/// # Arguments
+///
+/// * `min_delay`: [u64] - The minimum number of seconds which must elapse before a scheduled change may be executed.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When the minimum delay has already been set.
+/// * When `min_delay` is zero.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::timelock::{_proxy_delay, initialize_proxy_delay};
+///
+/// fn foo() {
+///     initialize_proxy_delay(86400);
+///     assert(_proxy_delay() == Some(86400));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn initialize_proxy_delay(min_delay: u64) {
+    only_proxy_owner();
+
+    require(
+        _proxy_delay().is_none(),
+        ProxyTimelockError::DelayAlreadyInitialized,
+    );
+    require(min_delay > 0, ProxyTimelockError::DelayTooShort);
+
+    proxy_delay_key().write(min_delay);
+
+    log(ProxyDelaySet { min_delay });
+}
+
+/// Returns the scheduled proxy owner.
+///
+/// # Returns
+///
+/// * [Option<(State, u64)>] - The scheduled state of the proxy ownership and the earliest timestamp at which it may be executed, or `None` if no change is scheduled.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::timelock::_scheduled_proxy_owner;
+///
+/// fn foo() {
+///     let scheduled = _scheduled_proxy_owner();
+/// }
+/// ```
+#[storage(read)]
+pub fn _scheduled_proxy_owner() -> Option<(State, u64)> {
+    scheduled_proxy_owner_key().try_read()
+}
+
+/// Schedules a change of the proxy ownership to the passed State after a delay.
+///
+/// # Additional Information
+///
+/// The change may be executed with `execute_proxy_owner()` once the block timestamp has reached the current block timestamp plus `delay`. Scheduling a new change replaces any previously scheduled change.
+///
+/// The minimum delay must first be set with `initialize_proxy_delay()`.
+///
+/// # Arguments
+///
+/// * `new_proxy_owner`: [State] - The new state of the proxy ownership.
+/// * `delay`: [u64] - The number of seconds which must elapse before the change may be executed.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When the new state of the proxy ownership is Uninitialized.
+/// * When the minimum delay has not been set.
+/// * When `delay` is less than the minimum delay.
+/// * When the current block timestamp plus `delay` overflows.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::timelock::{_scheduled_proxy_owner, schedule_proxy_owner};
+///
+/// fn foo(new_owner: Identity) {
+///     schedule_proxy_owner(State::Initialized(new_owner), 86400);
+///     assert(_scheduled_proxy_owner().is_some());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn schedule_proxy_owner(new_proxy_owner: State, delay: u64) {
+    only_proxy_owner();
+
+    require(
+        new_proxy_owner != State::Uninitialized,
+        SetProxyOwnerError::CannotUninitialize,
+    );
+
+    let eta = delayed_timestamp(delay);
+    scheduled_proxy_owner_key().write((new_proxy_owner, eta));
+
+    log(ProxyOwnerScheduled {
+        new_proxy_owner,
+        eta,
+    });
+}
+
+/// Executes a scheduled change of the proxy ownership.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When there is no scheduled change.
+/// * When the delay of the scheduled change has not elapsed.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `3`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_owner, timelock::execute_proxy_owner};
+///
+/// fn foo(new_owner: Identity) {
+///     execute_proxy_owner();
+///     assert(_proxy_owner() == State::Initialized(new_owner));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn execute_proxy_owner() {
+    only_proxy_owner();
+
+    let scheduled = _scheduled_proxy_owner();
+    require(scheduled.is_some(), ProxyTimelockError::NotScheduled);
+
+    let (new_proxy_owner, eta) = scheduled.unwrap();
+    require(timestamp() >= eta, ProxyTimelockError::NotElapsed);
+
+    // Clears the scheduled proxy owner.
+    _set_proxy_owner(new_proxy_owner);
+
+    log(ScheduledProxyOwnerExecuted {
+        new_proxy_owner,
+    });
+}
+
+/// Cancels a scheduled change of the proxy ownership.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When there is no scheduled change.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::timelock::{_scheduled_proxy_owner, cancel_scheduled_proxy_owner};
+///
+/// fn foo() {
+///     cancel_scheduled_proxy_owner();
+///     assert(_scheduled_proxy_owner().is_none());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn cancel_scheduled_proxy_owner() {
+    only_proxy_owner();
+
+    let scheduled = _scheduled_proxy_owner();
+    require(scheduled.is_some(), ProxyTimelockError::NotScheduled);
+
+    let _ = scheduled_proxy_owner_key().clear();
+
+    log(ScheduledProxyOwnerCancelled {
+        new_proxy_owner: scheduled.unwrap().0,
+    });
+}
+
+/// Returns the pending proxy target.
+///
+/// # Returns
+///
+/// * [Option<(ContractId, u64)>] - The pending target contract and the earliest timestamp at which it may be executed, or `None` if no upgrade is scheduled.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::timelock::_pending_proxy_target;
+///
+/// fn foo() {
+///     let pending = _pending_proxy_target();
+/// }
+/// ```
+#[storage(read)]
+pub fn _pending_proxy_target() -> Option<(ContractId, u64)> {
+    pending_proxy_target_key().try_read()
+}
+
+/// Schedules an upgrade of the proxy target which may be executed at or after `eta`.
+///
+/// # Additional Information
+///
+/// Scheduling a new upgrade replaces any previously scheduled upgrade.
+///
+/// The minimum delay must first be set with `initialize_proxy_delay()` and `eta` must be at least the minimum delay after the current block timestamp.
+///
+/// **Note:** The timelock only applies to upgrades made through this module. `_set_proxy_target()`, `rollback_proxy_target()`, and `_set_proxy_target_verified()` change the proxy target immediately and should not be exposed by a proxy which relies on the timelock.
+///
+/// # Arguments
+///
+/// * `new_target`: [ContractId] - The new proxy contract to which all fallback calls will be passed.
+/// * `eta`: [u64] - The earliest block timestamp at which the upgrade may be executed.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When `eta` is before the current block timestamp.
+/// * When the minimum delay has not been set.
+/// * When `eta` is less than the minimum delay after the current block timestamp.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use std::block::timestamp;
+/// use upgradability::timelock::{_pending_proxy_target, schedule_proxy_target};
+///
+/// fn foo(new_target: ContractId) {
+///     let eta = timestamp() + 86400;
+///     schedule_proxy_target(new_target, eta);
+///     assert(_pending_proxy_target() == Some((new_target, eta)));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn schedule_proxy_target(new_target: ContractId, eta: u64) {
+    only_proxy_owner();
+
+    let now = timestamp();
+    require(eta >= now, ProxyTimelockError::InvalidEta);
+    require_min_delay(eta - now);
+
+    pending_proxy_target_key().write((new_target, eta));
+
+    log(ProxyTargetScheduled { new_target, eta });
+}
+
+/// Executes a scheduled upgrade of the proxy target.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When there is no scheduled upgrade.
+/// * When the `eta` of the scheduled upgrade has not been reached.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_target, timelock::execute_proxy_target};
+///
+/// fn foo(new_target: ContractId) {
+///     execute_proxy_target();
+///     assert(_proxy_target() == Some(new_target));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn execute_proxy_target() {
+    only_proxy_owner();
+
+    let pending = _pending_proxy_target();
+    require(pending.is_some(), ProxyTimelockError::NotScheduled);
+
+    let (new_target, eta) = pending.unwrap();
+    require(timestamp() >= eta, ProxyTimelockError::NotElapsed);
+
+    let _ = pending_proxy_target_key().clear();
+
+    _set_proxy_target(new_target);
+
+    log(ScheduledProxyTargetExecuted { new_target });
+}
+
+/// Cancels a scheduled upgrade of the proxy target.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When there is no scheduled upgrade.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::timelock::{_pending_proxy_target, cancel_proxy_target};
+///
+/// fn foo() {
+///     cancel_proxy_target();
+///     assert(_pending_proxy_target().is_none());
+/// }
+/// ```
+#[storage(read, write)]
+pub fn cancel_proxy_target() {
+    only_proxy_owner();
+
+    let pending = _pending_proxy_target();
+    require(pending.is_some(), ProxyTimelockError::NotScheduled);
+
+    let _ = pending_proxy_target_key().clear();
+
+    log(ScheduledProxyTargetCancelled {
+        new_target: pending.unwrap().0,
+    });
+}
+
+fn scheduled_proxy_owner_key() -> StorageKey<(State, u64)> {
+    StorageKey::new(
+        SCHEDULED_PROXY_OWNER_STORAGE,
+        0,
+        SCHEDULED_PROXY_OWNER_STORAGE,
+    )
+}
+
+fn pending_proxy_target_key() -> StorageKey<(ContractId, u64)> {
+    StorageKey::new(
+        PENDING_PROXY_TARGET_STORAGE,
+        0,
+        PENDING_PROXY_TARGET_STORAGE,
+    )
+}
+
+fn proxy_delay_key() -> StorageKey<u64> {
+    StorageKey::new(PROXY_DELAY_STORAGE, 0, PROXY_DELAY_STORAGE)
+}
+
+#[storage(read)]
+fn require_min_delay(delay: u64) {
+    let min_delay = _proxy_delay();
+    require(min_delay.is_some(), ProxyTimelockError::DelayNotInitialized);
+    require(delay >= min_delay.unwrap(), ProxyTimelockError::DelayTooShort);
+}
+
+#[storage(read)]
+fn delayed_timestamp(delay: u64) -> u64 {
+    require_min_delay(delay);
+
+    let now = timestamp();
+    require(delay <= u64::max() - now, ProxyTimelockError::DelayOverflow);
+
+    now + delay
+}
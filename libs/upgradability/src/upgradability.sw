@@ -2,9 +2,37 @@ library;
 
 pub mod errors;
 pub mod events;
+pub mod timelock;
+pub mod verification;
 
-use ::{errors::SetProxyOwnerError, events::{ProxyOwnerSet, ProxyTargetSet}};
-use std::{auth::msg_sender, storage::storage_api::{read, write}};
+use ::{
+    errors::{
+        InitializationError,
+        PendingProxyOwnerError,
+        ProxyTargetHistoryError,
+        SetProxyOwnerError,
+    },
+    events::{
+        ProxyOwnerProposed,
+        ProxyOwnerRenounced,
+        ProxyOwnerSet,
+        ProxyTargetRolledBack,
+        ProxyTargetSet,
+    },
+    timelock::SCHEDULED_PROXY_OWNER_STORAGE,
+};
+use std::{
+    auth::msg_sender,
+    block::height,
+    storage::{
+        storage_api::{
+            clear,
+            read,
+            write,
+        },
+        storage_vec::*,
+    },
+};
 use src14::SRC14_TARGET_STORAGE;
 use src5::{AccessError, State};
 
@@ -13,6 +41,16 @@ use src5::{AccessError, State};
 /// Value is `sha256("storage_SRC14_1")`.
 pub const PROXY_OWNER_STORAGE: b256 = 0xbb79927b15d9259ea316f2ecb2297d6cc8851888a98278c0a2e03e1a091ea754;
 
+/// The storage slot to store the pending proxy owner Identity.
+///
+/// Value is `sha256("storage_SRC14_pending_proxy_owner")`.
+pub const PENDING_PROXY_OWNER_STORAGE: b256 = 0xd492ea99010f00ccb87131bd1a8a09597888356f8c75b49582ba2186afe327e7;
+
+/// The storage slot to store the history of proxy targets.
+///
+/// Value is `sha256("storage_SRC14_proxy_target_history")`.
+pub const PROXY_TARGET_HISTORY_STORAGE: b256 = 0x5bde4134d4ceb2c55a2444dee9963319841bd3034a404192a5dc93ef7083fdd2;
+
 /// Returns the proxy target.
 ///
 /// # Returns
@@ -40,13 +78,21 @@ pub fn _proxy_target() -> Option<ContractId> {
 
 /// Change the target contract of a proxy contract.
 ///
+/// # Additional Information
+///
+/// The new target and the current block height are appended to the proxy target history.
+/// If the history is empty, the current proxy target is recorded first with a block height of `0` so that it may be restored with `rollback_proxy_target()`.
+///
+/// **Note:** The target is changed immediately and bypasses the `timelock` module. A proxy which schedules upgrades with `schedule_proxy_target()` should not expose this function.
+///
 /// # Arguments
 ///
 /// * `new_target`: [ContractId] - The new proxy contract to which all fallback calls will be passed.
 ///
 /// # Number of Storage Accesses
 ///
-/// * Writes: `1`
+/// * Reads: `4`
+/// * Writes: `5`
 ///
 /// # Examples
 ///
@@ -62,16 +108,151 @@ pub fn _proxy_target() -> Option<ContractId> {
 ///     assert(_proxy_target() == Some(new_target));
 /// }
 /// ```
-#[storage(write)]
+#[storage(read, write)]
 pub fn _set_proxy_target(new_target: ContractId) {
     let proxy_target_key = StorageKey::new(SRC14_TARGET_STORAGE, 0, SRC14_TARGET_STORAGE);
+    let history_key = proxy_target_history_key();
+
+    // Record a target which was set directly in storage so that it may be rolled back to.
+    // The height at which it was set is unknown, so `0` is recorded instead.
+    if history_key.len() == 0 {
+        match proxy_target_key.try_read() {
+            Some(Some(current_target)) => history_key.push((current_target, 0)),
+            _ => (),
+        }
+    }
+
     proxy_target_key.write(Some(new_target));
+    history_key.push((new_target, height()));
 
     log(ProxyTargetSet { new_target });
 }
 
+/// Returns an entry of the proxy target history.
+///
+/// # Additional Information
+///
+/// The history is append-only. An entry is appended every time the proxy target is changed with `_set_proxy_target()` or `rollback_proxy_target()`.
+/// A target set directly in storage, such as an initial target set by a `configurable`, is recorded with a block height of `0` by the first call to `_set_proxy_target()`.
+///
+/// # Arguments
+///
+/// * `index`: [u64] - The position in the history, where `0` is the oldest entry.
+///
+/// # Returns
+///
+/// * [Option<(ContractId, u32)>] - The target and the block height at which it was set, or `None` if `index` is out of bounds.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_target_history, _proxy_target_history_len, _set_proxy_target};
+///
+/// fn foo(new_target: ContractId) {
+///     _set_proxy_target(new_target);
+///
+///     let (target, _block_height) = _proxy_target_history(_proxy_target_history_len() - 1).unwrap();
+///     assert(target == new_target);
+/// }
+/// ```
+#[storage(read)]
+pub fn _proxy_target_history(index: u64) -> Option<(ContractId, u32)> {
+    match proxy_target_history_key().get(index) {
+        Some(entry_key) => Some(entry_key.read()),
+        None => None,
+    }
+}
+
+/// Returns the number of entries in the proxy target history.
+///
+/// # Returns
+///
+/// * [u64] - The number of times the proxy target has been changed.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_target_history_len, _set_proxy_target};
+///
+/// fn foo(first_target: ContractId, second_target: ContractId) {
+///     _set_proxy_target(first_target);
+///     let len = _proxy_target_history_len();
+///
+///     _set_proxy_target(second_target);
+///     assert(_proxy_target_history_len() == len + 1);
+/// }
+/// ```
+#[storage(read)]
+pub fn _proxy_target_history_len() -> u64 {
+    proxy_target_history_key().len()
+}
+
+/// Restores the proxy target which was set before the current one.
+///
+/// # Additional Information
+///
+/// The restored target and the current block height are appended to the proxy target history, so the rollback itself is recorded.
+/// As the rollback becomes the latest entry, calling this function again restores the target which was rolled back.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When the proxy target history has fewer than two entries.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `6`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_target, _set_proxy_target, rollback_proxy_target};
+///
+/// fn foo(first_target: ContractId, second_target: ContractId) {
+///     _set_proxy_target(first_target);
+///     _set_proxy_target(second_target);
+///
+///     rollback_proxy_target();
+///     assert(_proxy_target() == Some(first_target));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn rollback_proxy_target() {
+    only_proxy_owner();
+
+    let history_key = proxy_target_history_key();
+    let len = history_key.len();
+    require(len > 1, ProxyTargetHistoryError::NoPreviousTarget);
+
+    let (current_target, _) = history_key.get(len - 1).unwrap().read();
+    let (previous_target, _) = history_key.get(len - 2).unwrap().read();
+
+    let proxy_target_key = StorageKey::new(SRC14_TARGET_STORAGE, 0, SRC14_TARGET_STORAGE);
+    proxy_target_key.write(Some(previous_target));
+    history_key.push((previous_target, height()));
+
+    log(ProxyTargetSet { new_target: previous_target });
+    log(ProxyTargetRolledBack {
+        previous_target: current_target,
+        new_target: previous_target,
+    });
+}
+
 /// Returns the owner of the proxy.
 ///
+/// # Additional Information
+///
+/// If the proxy owner has never been written to storage, `State::Uninitialized` is returned.
+///
 /// # Returns
 ///
 /// * [State] - The state of the proxy ownership.
@@ -93,7 +274,7 @@ pub fn _set_proxy_target(new_target: ContractId) {
 #[storage(read)]
 pub fn _proxy_owner() -> State {
     let proxy_owner_key = StorageKey::new(PROXY_OWNER_STORAGE, 0, PROXY_OWNER_STORAGE);
-    proxy_owner_key.read()
+    proxy_owner_key.try_read().unwrap_or(State::Uninitialized)
 }
 
 /// Ensures that the sender is the proxy owner.
@@ -124,11 +305,58 @@ pub fn only_proxy_owner() {
     );
 }
 
+/// Sets the passed identity as the initial proxy owner.
+///
+/// # Additional Information
+///
+/// This may be used in a constructor of the proxy contract instead of writing the proxy owner storage slot at deployment.
+///
+/// # Arguments
+///
+/// * `new_proxy_owner`: [Identity] - The `Identity` that will be the first proxy owner.
+///
+/// # Reverts
+///
+/// * When the proxy owner has been set before.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_owner, initialize_proxy_owner};
+///
+/// fn foo(owner: Identity) {
+///     assert(_proxy_owner() == State::Uninitialized);
+///     initialize_proxy_owner(owner);
+///     assert(_proxy_owner() == State::Initialized(owner));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn initialize_proxy_owner(new_proxy_owner: Identity) {
+    require(
+        _proxy_owner() == State::Uninitialized,
+        InitializationError::CannotReinitialized,
+    );
+
+    let new_proxy_owner = State::Initialized(new_proxy_owner);
+    let proxy_owner_key = StorageKey::new(PROXY_OWNER_STORAGE, 0, PROXY_OWNER_STORAGE);
+    proxy_owner_key.write(new_proxy_owner);
+
+    log(ProxyOwnerSet {
+        new_proxy_owner,
+    });
+}
+
 /// Change proxy ownership to the passed State.
 ///
 /// # Additional Information
 ///
 /// This function can be used to transfer ownership between Identities or to revoke ownership.
+/// Any pending or scheduled proxy owner is cleared.
 ///
 /// # Arguments
 ///
@@ -141,7 +369,8 @@ pub fn only_proxy_owner() {
 ///
 /// # Number of Storage Accesses
 ///
-/// * Writes: `1`
+/// * Reads: `1`
+/// * Writes: `3`
 ///
 /// # Examples
 ///
@@ -169,7 +398,185 @@ pub fn _set_proxy_owner(new_proxy_owner: State) {
     let proxy_owner_key = StorageKey::new(PROXY_OWNER_STORAGE, 0, PROXY_OWNER_STORAGE);
     proxy_owner_key.write(new_proxy_owner);
 
+    let _ = pending_proxy_owner_key().clear();
+    let _ = scheduled_proxy_owner_key().clear();
+
+    log(ProxyOwnerSet {
+        new_proxy_owner,
+    });
+}
+
+/// Revokes the proxy ownership.
+///
+/// # Additional Information
+///
+/// Once renounced, the proxy owner can no longer be changed and functions guarded by `only_proxy_owner()` can no longer be called.
+/// Any pending or scheduled proxy owner is cleared.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_owner, renounce_proxy_owner};
+///
+/// fn foo() {
+///     renounce_proxy_owner();
+///     assert(_proxy_owner() == State::Revoked);
+/// }
+/// ```
+#[storage(read, write)]
+pub fn renounce_proxy_owner() {
+    only_proxy_owner();
+
+    let proxy_owner_key = StorageKey::new(PROXY_OWNER_STORAGE, 0, PROXY_OWNER_STORAGE);
+    proxy_owner_key.write(State::Revoked);
+
+    let _ = pending_proxy_owner_key().clear();
+    let _ = scheduled_proxy_owner_key().clear();
+
+    log(ProxyOwnerRenounced {
+        previous_proxy_owner: msg_sender().unwrap(),
+    });
+}
+
+/// Returns the pending proxy owner.
+///
+/// # Returns
+///
+/// * [Option<Identity>] - The `Identity` which may accept the proxy ownership, or `None` if there is no pending proxy owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::_pending_proxy_owner;
+///
+/// fn foo() {
+///     let pending_proxy_owner = _pending_proxy_owner();
+/// }
+/// ```
+#[storage(read)]
+pub fn _pending_proxy_owner() -> Option<Identity> {
+    pending_proxy_owner_key().try_read()
+}
+
+/// Proposes a new proxy owner which must accept the proxy ownership with `accept_proxy_owner()`.
+///
+/// # Additional Information
+///
+/// The proxy ownership does not change until the proposal is accepted. Proposing a new proxy owner replaces any previous proposal.
+///
+/// # Arguments
+///
+/// * `new_proxy_owner`: [Identity] - The `Identity` which may accept the proxy ownership.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_pending_proxy_owner, propose_proxy_owner};
+///
+/// fn foo(new_proxy_owner: Identity) {
+///     propose_proxy_owner(new_proxy_owner);
+///     assert(_pending_proxy_owner() == Some(new_proxy_owner));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn propose_proxy_owner(new_proxy_owner: Identity) {
+    only_proxy_owner();
+
+    pending_proxy_owner_key().write(new_proxy_owner);
+
+    log(ProxyOwnerProposed {
+        pending_proxy_owner: new_proxy_owner,
+        proxy_owner: msg_sender().unwrap(),
+    });
+}
+
+/// Accepts a proposed proxy ownership, making the sender the proxy owner.
+///
+/// # Additional Information
+///
+/// Any scheduled proxy owner is cleared.
+///
+/// # Reverts
+///
+/// * When the sender is not the pending proxy owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `3`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_owner, accept_proxy_owner};
+///
+/// fn foo() {
+///     accept_proxy_owner();
+///     assert(_proxy_owner() == State::Initialized(msg_sender().unwrap()));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn accept_proxy_owner() {
+    let sender = msg_sender().unwrap();
+    require(
+        _pending_proxy_owner() == Some(sender),
+        PendingProxyOwnerError::NotPendingProxyOwner,
+    );
+
+    let new_proxy_owner = State::Initialized(sender);
+    let proxy_owner_key = StorageKey::new(PROXY_OWNER_STORAGE, 0, PROXY_OWNER_STORAGE);
+    proxy_owner_key.write(new_proxy_owner);
+
+    let _ = pending_proxy_owner_key().clear();
+    let _ = scheduled_proxy_owner_key().clear();
+
     log(ProxyOwnerSet {
         new_proxy_owner,
     });
 }
+
+fn scheduled_proxy_owner_key() -> StorageKey<(State, u64)> {
+    StorageKey::new(
+        SCHEDULED_PROXY_OWNER_STORAGE,
+        0,
+        SCHEDULED_PROXY_OWNER_STORAGE,
+    )
+}
+
+fn pending_proxy_owner_key() -> StorageKey<Identity> {
+    StorageKey::new(
+        PENDING_PROXY_OWNER_STORAGE,
+        0,
+        PENDING_PROXY_OWNER_STORAGE,
+    )
+}
+
+fn proxy_target_history_key() -> StorageKey<StorageVec<(ContractId, u32)>> {
+    StorageKey::new(
+        PROXY_TARGET_HISTORY_STORAGE,
+        0,
+        PROXY_TARGET_HISTORY_STORAGE,
+    )
+}
@@ -0,0 +1,161 @@
+library;
+
+use ::{_set_proxy_target, only_proxy_owner};
+use ::errors::ProxyTargetVerificationError;
+use ::events::{ImplementationRootApproved, ImplementationRootRevoked};
+use std::{external::bytecode_root, hash::{Hash, sha256}};
+
+/// Returns whether a bytecode root has been approved by the proxy owner.
+///
+/// # Arguments
+///
+/// * `root`: [b256] - The bytecode root of an implementation contract.
+///
+/// # Returns
+///
+/// * [bool] - `true` if `root` has been approved, otherwise `false`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::verification::_is_implementation_root_approved;
+///
+/// fn foo(root: b256) {
+///     let approved = _is_implementation_root_approved(root);
+/// }
+/// ```
+#[storage(read)]
+pub fn _is_implementation_root_approved(root: b256) -> bool {
+    approved_root_key(root).try_read().unwrap_or(false)
+}
+
+/// Adds a bytecode root to the allowlist of approved implementations.
+///
+/// # Arguments
+///
+/// * `root`: [b256] - The bytecode root of an implementation contract.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::verification::{_is_implementation_root_approved, approve_implementation_root};
+///
+/// fn foo(root: b256) {
+///     approve_implementation_root(root);
+///     assert(_is_implementation_root_approved(root));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn approve_implementation_root(root: b256) {
+    only_proxy_owner();
+
+    approved_root_key(root).write(true);
+
+    log(ImplementationRootApproved { root });
+}
+
+/// Removes a bytecode root from the allowlist of approved implementations.
+///
+/// # Additional Information
+///
+/// Revoking a root does not change the current proxy target.
+///
+/// # Arguments
+///
+/// * `root`: [b256] - The bytecode root of an implementation contract.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `1`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::verification::{_is_implementation_root_approved, revoke_implementation_root};
+///
+/// fn foo(root: b256) {
+///     revoke_implementation_root(root);
+///     assert(!_is_implementation_root_approved(root));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn revoke_implementation_root(root: b256) {
+    only_proxy_owner();
+
+    let _ = approved_root_key(root).clear();
+
+    log(ImplementationRootRevoked { root });
+}
+
+/// Change the target contract of a proxy contract after verifying its bytecode.
+///
+/// # Additional Information
+///
+/// The bytecode root of the deployed `new_target` must match `expected_root` and `expected_root` must have been approved with `approve_implementation_root()`.
+///
+/// **Note:** The target is changed immediately and bypasses the `timelock` module. A proxy which schedules upgrades with `schedule_proxy_target()` should not expose this function.
+///
+/// # Arguments
+///
+/// * `new_target`: [ContractId] - The new proxy contract to which all fallback calls will be passed.
+/// * `expected_root`: [b256] - The approved bytecode root which `new_target` is expected to have.
+///
+/// # Reverts
+///
+/// * When the sender is not the proxy owner.
+/// * When `expected_root` has not been approved.
+/// * When the bytecode root of `new_target` does not match `expected_root`.
+///
+/// # Number of Storage Accesses
+///
+/// * Reads: `2`
+/// * Writes: `1`
+///
+/// # Examples
+///
+/// ```sway
+/// use upgradability::{_proxy_target, verification::_set_proxy_target_verified};
+///
+/// fn foo(new_target: ContractId, expected_root: b256) {
+///     _set_proxy_target_verified(new_target, expected_root);
+///     assert(_proxy_target() == Some(new_target));
+/// }
+/// ```
+#[storage(read, write)]
+pub fn _set_proxy_target_verified(new_target: ContractId, expected_root: b256) {
+    only_proxy_owner();
+
+    require(
+        _is_implementation_root_approved(expected_root),
+        ProxyTargetVerificationError::RootNotApproved,
+    );
+    require(
+        bytecode_root(new_target) == expected_root,
+        ProxyTargetVerificationError::RootMismatch,
+    );
+
+    _set_proxy_target(new_target);
+}
+
+fn approved_root_key(root: b256) -> StorageKey<bool> {
+    let key_digest = sha256(("storage_SRC14_approved_root", root));
+    StorageKey::new(key_digest, 0, key_digest)
+}
@@ -0,0 +1,37 @@
+//! Off-chain derivation of sub-identifiers and asset IDs matching the Asset Library's `sub_id` module.
+
+use fuels::types::{AssetId, Bits256, ContractId};
+use sha2::{Digest, Sha256};
+
+/// Derives a sub-identifier from a serial number, matching `sub_id_from_serial()`.
+pub fn sub_id_from_serial(serial: u64) -> Bits256 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"serial");
+    hasher.update(serial.to_be_bytes());
+    Bits256(hasher.finalize().into())
+}
+
+/// Derives a sub-identifier from a string, matching `sub_id_from_string()`.
+pub fn sub_id_from_string(name: &str) -> Bits256 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"string");
+    hasher.update(name.as_bytes());
+    Bits256(hasher.finalize().into())
+}
+
+/// Derives a sub-identifier from a collection and an index, matching `sub_id_from_collection()`.
+pub fn sub_id_from_collection(collection: Bits256, index: u64) -> Bits256 {
+    let mut hasher = Sha256::new();
+    hasher.update(b"collection");
+    hasher.update(collection.0);
+    hasher.update(index.to_be_bytes());
+    Bits256(hasher.finalize().into())
+}
+
+/// Returns the `AssetId` of the asset with `sub_id` minted by `contract`, matching `AssetId::new()`.
+pub fn asset_id(contract: ContractId, sub_id: Bits256) -> AssetId {
+    let mut hasher = Sha256::new();
+    hasher.update(*contract);
+    hasher.update(sub_id.0);
+    AssetId::new(hasher.finalize().into())
+}
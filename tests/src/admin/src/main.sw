@@ -14,6 +14,12 @@ abi AdminTest {
     fn only_admin();
     #[storage(read)]
     fn only_owner_or_admin();
+    #[storage(read)]
+    fn admins_len() -> u64;
+    #[storage(read)]
+    fn admin_at(index: u64) -> Option<Identity>;
+    #[storage(read)]
+    fn admins(start: u64, count: u64) -> Vec<Identity>;
 }
 
 abi OwnableTest {
@@ -52,6 +58,21 @@ impl AdminTest for Contract {
     fn only_owner_or_admin() {
         only_owner_or_admin();
     }
+
+    #[storage(read)]
+    fn admins_len() -> u64 {
+        admins_len()
+    }
+
+    #[storage(read)]
+    fn admin_at(index: u64) -> Option<Identity> {
+        admin_at(index)
+    }
+
+    #[storage(read)]
+    fn admins(start: u64, count: u64) -> Vec<Identity> {
+        admins(start, count)
+    }
 }
 
 impl OwnableTest for Contract {
@@ -1,8 +1,9 @@
 use crate::admin::tests::utils::{
     abi_calls::{add_admin, is_admin, set_ownership},
     test_helpers::setup,
+    AdminAdded, AdminRevoked,
 };
-use fuels::types::Identity;
+use fuels::types::{ContractId, Identity};
 
 mod success {
 
@@ -17,8 +18,18 @@ mod success {
         set_ownership(&owner.contract, owner_identity.clone()).await;
 
         assert!(!is_admin(&owner.contract, admin1_identity.clone()).await);
-        add_admin(&owner.contract, admin1_identity.clone()).await;
+        let response = add_admin(&owner.contract, admin1_identity.clone()).await;
         assert!(is_admin(&owner.contract, admin1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<AdminAdded>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            AdminAdded {
+                new_admin: admin1_identity,
+                owner: owner_identity,
+            }
+        );
     }
 
     #[tokio::test]
@@ -33,11 +44,73 @@ mod success {
         assert!(!is_admin(&owner.contract, admin1_identity.clone()).await);
         assert!(!is_admin(&owner.contract, admin2_identity.clone()).await);
 
-        add_admin(&owner.contract, admin1_identity.clone()).await;
-        add_admin(&owner.contract, admin2_identity.clone()).await;
+        let response1 = add_admin(&owner.contract, admin1_identity.clone()).await;
+        let response2 = add_admin(&owner.contract, admin2_identity.clone()).await;
 
         assert!(is_admin(&owner.contract, admin1_identity.clone()).await);
         assert!(is_admin(&owner.contract, admin2_identity.clone()).await);
+
+        let log1 = response1.decode_logs_with_type::<AdminAdded>().unwrap();
+        let event1 = log1.first().unwrap();
+        assert_eq!(
+            *event1,
+            AdminAdded {
+                new_admin: admin1_identity,
+                owner: owner_identity.clone(),
+            }
+        );
+
+        let log2 = response2.decode_logs_with_type::<AdminAdded>().unwrap();
+        let event2 = log2.first().unwrap();
+        assert_eq!(
+            *event2,
+            AdminAdded {
+                new_admin: admin2_identity,
+                owner: owner_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_log_when_already_admin() {
+        let (_deployer, owner, admin1, _admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+
+        let response = add_admin(&owner.contract, admin1_identity.clone()).await;
+        assert!(is_admin(&owner.contract, admin1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<AdminAdded>().unwrap();
+        assert!(log.is_empty());
+    }
+
+    #[tokio::test]
+    async fn replaces_admin_with_same_bits() {
+        let (_deployer, owner, admin1, _admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let address_identity = Identity::Address(admin1.wallet.address().into());
+        let contract_identity =
+            Identity::ContractId(ContractId::new(*admin1.wallet.address().hash()));
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, address_identity.clone()).await;
+
+        let response = add_admin(&owner.contract, contract_identity.clone()).await;
+        assert!(is_admin(&owner.contract, contract_identity.clone()).await);
+        assert!(!is_admin(&owner.contract, address_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<AdminRevoked>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            AdminRevoked {
+                old_admin: address_identity,
+                owner: owner_identity,
+            }
+        );
     }
 }
 
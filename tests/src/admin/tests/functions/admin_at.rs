@@ -0,0 +1,130 @@
+use crate::admin::tests::utils::{
+    abi_calls::{add_admin, admin_at, remove_admin, set_ownership},
+    test_helpers::setup,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_no_admins() {
+        let (_deployer, owner, _admin1, _admin2) = setup().await;
+
+        assert_eq!(admin_at(&owner.contract, 0).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_admins_in_order_added() {
+        let (deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        let admin3_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin3_identity.clone()).await;
+
+        assert_eq!(admin_at(&owner.contract, 0).await, Some(admin1_identity));
+        assert_eq!(admin_at(&owner.contract, 1).await, Some(admin2_identity));
+        assert_eq!(admin_at(&owner.contract, 2).await, Some(admin3_identity));
+        assert_eq!(admin_at(&owner.contract, 3).await, None);
+    }
+
+    #[tokio::test]
+    async fn moves_last_admin_when_removing_first() {
+        let (deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        let admin3_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin3_identity.clone()).await;
+
+        remove_admin(&owner.contract, admin1_identity.clone()).await;
+
+        assert_eq!(
+            admin_at(&owner.contract, 0).await,
+            Some(admin3_identity.clone())
+        );
+        assert_eq!(admin_at(&owner.contract, 1).await, Some(admin2_identity));
+        assert_eq!(admin_at(&owner.contract, 2).await, None);
+    }
+
+    #[tokio::test]
+    async fn keeps_order_when_removing_last() {
+        let (deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        let admin3_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin3_identity.clone()).await;
+
+        remove_admin(&owner.contract, admin3_identity.clone()).await;
+
+        assert_eq!(admin_at(&owner.contract, 0).await, Some(admin1_identity));
+        assert_eq!(admin_at(&owner.contract, 1).await, Some(admin2_identity));
+        assert_eq!(admin_at(&owner.contract, 2).await, None);
+    }
+
+    #[tokio::test]
+    async fn appends_readded_admin() {
+        let (deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        let admin3_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin3_identity.clone()).await;
+
+        remove_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+
+        assert_eq!(admin_at(&owner.contract, 0).await, Some(admin1_identity));
+        assert_eq!(admin_at(&owner.contract, 1).await, Some(admin3_identity));
+        assert_eq!(admin_at(&owner.contract, 2).await, Some(admin2_identity));
+        assert_eq!(admin_at(&owner.contract, 3).await, None);
+    }
+
+    #[tokio::test]
+    async fn tracks_moved_admin_across_removals() {
+        let (deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        let admin3_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin3_identity.clone()).await;
+
+        // admin3 is moved to index 0
+        remove_admin(&owner.contract, admin1_identity.clone()).await;
+        // admin2 is moved to index 0
+        remove_admin(&owner.contract, admin3_identity.clone()).await;
+
+        assert_eq!(
+            admin_at(&owner.contract, 0).await,
+            Some(admin2_identity.clone())
+        );
+        assert_eq!(admin_at(&owner.contract, 1).await, None);
+
+        remove_admin(&owner.contract, admin2_identity.clone()).await;
+        assert_eq!(admin_at(&owner.contract, 0).await, None);
+    }
+}
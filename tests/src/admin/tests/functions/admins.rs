@@ -0,0 +1,98 @@
+use crate::admin::tests::utils::{
+    abi_calls::{add_admin, admins, remove_admin, set_ownership},
+    test_helpers::setup,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_empty_when_no_admins() {
+        let (_deployer, owner, _admin1, _admin2) = setup().await;
+
+        assert_eq!(admins(&owner.contract, 0, 10).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn returns_all_admins() {
+        let (deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        let admin3_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin3_identity.clone()).await;
+
+        assert_eq!(
+            admins(&owner.contract, 0, 3).await,
+            vec![admin1_identity, admin2_identity, admin3_identity]
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_page() {
+        let (deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        let admin3_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin3_identity.clone()).await;
+
+        assert_eq!(
+            admins(&owner.contract, 0, 2).await,
+            vec![admin1_identity.clone(), admin2_identity.clone()]
+        );
+        assert_eq!(
+            admins(&owner.contract, 1, 1).await,
+            vec![admin2_identity.clone()]
+        );
+        assert_eq!(
+            admins(&owner.contract, 2, 2).await,
+            vec![admin3_identity.clone()]
+        );
+        assert_eq!(admins(&owner.contract, 0, 0).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn returns_empty_when_start_out_of_bounds() {
+        let (_deployer, owner, admin1, _admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+
+        assert_eq!(admins(&owner.contract, 1, 10).await, vec![]);
+        assert_eq!(admins(&owner.contract, 5, 10).await, vec![]);
+    }
+
+    #[tokio::test]
+    async fn reflects_removals() {
+        let (deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        let admin3_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        add_admin(&owner.contract, admin3_identity.clone()).await;
+
+        remove_admin(&owner.contract, admin2_identity.clone()).await;
+
+        assert_eq!(
+            admins(&owner.contract, 0, 10).await,
+            vec![admin1_identity, admin3_identity]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::admin::tests::utils::{
+    abi_calls::{add_admin, admins_len, remove_admin, set_ownership},
+    test_helpers::setup,
+};
+use fuels::types::{ContractId, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_zero_when_no_admins() {
+        let (_deployer, owner, _admin1, _admin2) = setup().await;
+
+        assert_eq!(admins_len(&owner.contract).await, 0);
+    }
+
+    #[tokio::test]
+    async fn increments_on_add() {
+        let (_deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 1);
+
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_increment_when_admin_added_twice() {
+        let (_deployer, owner, admin1, _admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 1);
+    }
+
+    #[tokio::test]
+    async fn replaces_admin_with_same_bits() {
+        let (_deployer, owner, admin1, _admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let address_identity = Identity::Address(admin1.wallet.address().into());
+        let contract_identity =
+            Identity::ContractId(ContractId::new(*admin1.wallet.address().hash()));
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        add_admin(&owner.contract, address_identity.clone()).await;
+        add_admin(&owner.contract, contract_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 1);
+
+        remove_admin(&owner.contract, address_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 1);
+
+        remove_admin(&owner.contract, contract_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 0);
+    }
+
+    #[tokio::test]
+    async fn decrements_on_remove() {
+        let (_deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+        add_admin(&owner.contract, admin2_identity.clone()).await;
+
+        remove_admin(&owner.contract, admin1_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 1);
+
+        remove_admin(&owner.contract, admin2_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 0);
+    }
+
+    #[tokio::test]
+    async fn does_not_decrement_when_removing_non_admin() {
+        let (_deployer, owner, admin1, admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        let admin2_identity = Identity::Address(admin2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        add_admin(&owner.contract, admin1_identity.clone()).await;
+
+        remove_admin(&owner.contract, admin2_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 1);
+
+        remove_admin(&owner.contract, admin1_identity.clone()).await;
+        remove_admin(&owner.contract, admin1_identity.clone()).await;
+        assert_eq!(admins_len(&owner.contract).await, 0);
+    }
+}
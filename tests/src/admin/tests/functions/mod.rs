@@ -1,4 +1,7 @@
 mod add_admin;
+mod admin_at;
+mod admins;
+mod admins_len;
 mod is_admin;
 mod only_admin;
 mod only_owner_or_admin;
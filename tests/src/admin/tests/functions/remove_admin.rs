@@ -1,6 +1,7 @@
 use crate::admin::tests::utils::{
     abi_calls::{add_admin, is_admin, remove_admin, set_ownership},
     test_helpers::setup,
+    AdminRevoked,
 };
 use fuels::types::Identity;
 
@@ -18,8 +19,33 @@ mod success {
         add_admin(&owner.contract, admin1_identity.clone()).await;
 
         assert!(is_admin(&owner.contract, admin1_identity.clone()).await);
-        remove_admin(&owner.contract, admin1_identity.clone()).await;
+        let response = remove_admin(&owner.contract, admin1_identity.clone()).await;
         assert!(!is_admin(&owner.contract, admin1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<AdminRevoked>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            AdminRevoked {
+                old_admin: admin1_identity,
+                owner: owner_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_log_when_not_admin() {
+        let (_deployer, owner, admin1, _admin2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let admin1_identity = Identity::Address(admin1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        let response = remove_admin(&owner.contract, admin1_identity.clone()).await;
+        assert!(!is_admin(&owner.contract, admin1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<AdminRevoked>().unwrap();
+        assert!(log.is_empty());
     }
 }
 
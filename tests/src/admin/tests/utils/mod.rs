@@ -69,6 +69,34 @@ pub mod abi_calls {
             .unwrap()
     }
 
+    pub async fn admins_len(contract: &AdminLib<WalletUnlocked>) -> u64 {
+        contract.methods().admins_len().call().await.unwrap().value
+    }
+
+    pub async fn admin_at(contract: &AdminLib<WalletUnlocked>, index: u64) -> Option<Identity> {
+        contract
+            .methods()
+            .admin_at(index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn admins(
+        contract: &AdminLib<WalletUnlocked>,
+        start: u64,
+        count: u64,
+    ) -> Vec<Identity> {
+        contract
+            .methods()
+            .admins(start, count)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
     pub async fn set_ownership(
         contract: &AdminLib<WalletUnlocked>,
         new_owner: Identity,
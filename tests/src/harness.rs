@@ -5,4 +5,5 @@ mod merkle_proof;
 mod native_asset;
 mod ownership;
 mod reentrancy;
+mod roles;
 mod upgradability;
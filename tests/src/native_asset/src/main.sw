@@ -3,7 +3,19 @@ contract;
 use src20::SRC20;
 use src3::SRC3;
 use src7::{Metadata, SRC7};
+use admin::{add_admin, roles::grant_role};
+use ownership::initialize_ownership;
+use pausable::{_pause, _unpause};
+use guarded_asset::{_guarded_burn, _guarded_mint, AccessPolicy};
 use asset::{
+    allowance::{
+        _allowance,
+        _approve,
+        _deposit,
+        _ledger_balance,
+        _transfer_from,
+        _withdraw,
+    },
     base::{
         _decimals,
         _name,
@@ -16,9 +28,30 @@ use asset::{
         SetAssetAttributes,
     },
     metadata::*,
+    nft::{
+        _burn_nft,
+        _is_nft_minted,
+        _mint_nft,
+        _set_token_uri,
+        _token_uri,
+    },
+    sub_id::{
+        asset_id_from_sub_id,
+        sub_id_from_collection,
+        sub_id_from_serial,
+        sub_id_from_string,
+    },
     supply::{
+        _asset_at,
         _burn,
+        _burn_batch,
+        _burn_forwarded,
+        _max_supply,
         _mint,
+        _mint_batch,
+        _set_max_supply,
+        _sub_id_at,
+        _total_supply_at,
     },
 };
 use std::{hash::Hash, storage::storage_string::*, string::String};
@@ -26,10 +59,178 @@ use std::{hash::Hash, storage::storage_string::*, string::String};
 storage {
     total_assets: u64 = 0,
     total_supply: StorageMap<AssetId, u64> = StorageMap {},
+    max_supply: StorageMap<AssetId, u64> = StorageMap {},
     name: StorageMap<AssetId, StorageString> = StorageMap {},
     symbol: StorageMap<AssetId, StorageString> = StorageMap {},
     decimals: StorageMap<AssetId, u8> = StorageMap {},
     metadata: StorageMetadata = StorageMetadata {},
+    ledger_balances: StorageMap<(Identity, AssetId), u64> = StorageMap {},
+    allowances: StorageMap<(Identity, Identity, AssetId), u64> = StorageMap {},
+}
+
+abi MaxSupply {
+    #[storage(read)]
+    fn max_supply(asset: AssetId) -> Option<u64>;
+
+    #[storage(write)]
+    fn set_max_supply(asset: AssetId, max_supply: u64);
+}
+
+abi SubIds {
+    fn sub_id_from_serial(serial: u64) -> SubId;
+
+    fn sub_id_from_string(name: String) -> SubId;
+
+    fn sub_id_from_collection(collection: b256, index: u64) -> SubId;
+
+    fn asset_id_from_sub_id(sub_id: SubId) -> AssetId;
+}
+
+abi Guarded {
+    #[storage(read, write)]
+    fn guarded_mint(policy: AccessPolicy, recipient: Identity, sub_id: SubId, amount: u64);
+
+    #[payable]
+    #[storage(read, write)]
+    fn guarded_burn(policy: AccessPolicy, sub_id: SubId, amount: u64);
+
+    #[storage(read, write)]
+    fn initialize_ownership(owner: Identity);
+
+    #[storage(read, write)]
+    fn add_admin(admin: Identity);
+
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity);
+
+    #[storage(write)]
+    fn pause();
+
+    #[storage(write)]
+    fn unpause();
+}
+
+abi SupplyCheckpoints {
+    #[storage(read)]
+    fn total_supply_at(asset: AssetId, height: u32) -> Option<u64>;
+}
+
+abi BurnForwarded {
+    #[payable]
+    #[storage(read, write)]
+    fn burn_forwarded(sub_id: SubId) -> u64;
+}
+
+abi Allowance {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit(recipient: Identity) -> u64;
+
+    #[storage(read, write)]
+    fn withdraw(recipient: Identity, asset: AssetId, amount: u64);
+
+    #[storage(read)]
+    fn ledger_balance(owner: Identity, asset: AssetId) -> u64;
+
+    #[storage(write)]
+    fn approve(spender: Identity, asset: AssetId, amount: u64);
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity, asset: AssetId) -> u64;
+
+    #[storage(read, write)]
+    fn transfer_from(owner: Identity, recipient: Identity, asset: AssetId, amount: u64);
+}
+
+abi Batch {
+    #[storage(read, write)]
+    fn mint_batch(recipients: Vec<(Identity, SubId, u64)>);
+
+    #[storage(read, write)]
+    fn burn_batch(burns: Vec<(SubId, u64)>);
+}
+
+abi RemoveMetadata {
+    #[storage(read, write)]
+    fn remove_metadata(asset: AssetId, key: String) -> bool;
+}
+
+abi MetadataSchemas {
+    #[storage(write)]
+    fn set_metadata_schema(key: String, schema: MetadataSchema);
+
+    #[storage(write)]
+    fn remove_metadata_schema(key: String);
+
+    #[storage(read)]
+    fn metadata_schema(key: String) -> Option<MetadataSchema>;
+}
+
+abi DefaultMetadata {
+    #[storage(read, write)]
+    fn set_default_metadata(key: String, metadata: Metadata);
+
+    #[storage(read)]
+    fn default_metadata(key: String) -> Option<Metadata>;
+
+    #[storage(read)]
+    fn is_default_metadata(asset: AssetId, key: String) -> bool;
+}
+
+abi MetadataBatch {
+    #[storage(read, write)]
+    fn set_metadata_batch(entries: Vec<(AssetId, String, Metadata)>);
+
+    #[storage(read)]
+    fn metadata_batch(queries: Vec<(AssetId, String)>) -> Vec<Option<Metadata>>;
+}
+
+abi MetadataKeys {
+    #[storage(read)]
+    fn metadata_keys_len(asset: AssetId) -> u64;
+
+    #[storage(read)]
+    fn metadata_key_at(asset: AssetId, index: u64) -> Option<String>;
+}
+
+abi FreezeMetadata {
+    #[storage(read, write)]
+    fn freeze_metadata(asset: AssetId);
+
+    #[storage(read)]
+    fn is_metadata_frozen(asset: AssetId) -> bool;
+
+    #[storage(read, write)]
+    fn freeze_metadata_key(asset: AssetId, key: String);
+
+    #[storage(read)]
+    fn is_metadata_key_frozen(asset: AssetId, key: String) -> bool;
+}
+
+abi Enumeration {
+    #[storage(read)]
+    fn asset_at(index: u64) -> Option<AssetId>;
+
+    #[storage(read)]
+    fn sub_id_at(index: u64) -> Option<SubId>;
+}
+
+abi Nft {
+    #[storage(read, write)]
+    fn mint_nft(recipient: Identity, sub_id: SubId) -> AssetId;
+
+    #[payable]
+    #[storage(read, write)]
+    fn burn_nft(sub_id: SubId);
+
+    #[storage(read)]
+    fn is_nft_minted(asset: AssetId) -> bool;
+
+    #[storage(read, write)]
+    fn set_token_uri(asset: AssetId, uri: String);
+
+    #[storage(read)]
+    fn token_uri(asset: AssetId) -> Option<String>;
 }
 
 impl SRC20 for Contract {
@@ -67,6 +268,8 @@ impl SRC3 for Contract {
                 .total_assets,
             storage
                 .total_supply,
+            storage
+                .max_supply,
             recipient,
             sub_id
                 .unwrap_or(b256::zero()),
@@ -89,19 +292,19 @@ impl SRC7 for Contract {
 }
 
 impl SetAssetAttributes for Contract {
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_name(asset: AssetId, name: String) {
-        _set_name(storage.name, asset, name);
+        _set_name(storage.name, storage.metadata, asset, name);
     }
 
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_symbol(asset: AssetId, symbol: String) {
-        _set_symbol(storage.symbol, asset, symbol);
+        _set_symbol(storage.symbol, storage.metadata, asset, symbol);
     }
 
-    #[storage(write)]
+    #[storage(read, write)]
     fn set_decimals(asset: AssetId, decimals: u8) {
-        _set_decimals(storage.decimals, asset, decimals);
+        _set_decimals(storage.decimals, storage.metadata, asset, decimals);
     }
 }
 
@@ -112,6 +315,296 @@ impl SetAssetMetadata for Contract {
     }
 }
 
+impl MaxSupply for Contract {
+    #[storage(read)]
+    fn max_supply(asset: AssetId) -> Option<u64> {
+        _max_supply(storage.max_supply, asset)
+    }
+
+    #[storage(write)]
+    fn set_max_supply(asset: AssetId, max_supply: u64) {
+        _set_max_supply(storage.max_supply, asset, max_supply);
+    }
+}
+
+impl SubIds for Contract {
+    fn sub_id_from_serial(serial: u64) -> SubId {
+        sub_id_from_serial(serial)
+    }
+
+    fn sub_id_from_string(name: String) -> SubId {
+        sub_id_from_string(name)
+    }
+
+    fn sub_id_from_collection(collection: b256, index: u64) -> SubId {
+        sub_id_from_collection(collection, index)
+    }
+
+    fn asset_id_from_sub_id(sub_id: SubId) -> AssetId {
+        asset_id_from_sub_id(sub_id)
+    }
+}
+
+impl Guarded for Contract {
+    #[storage(read, write)]
+    fn guarded_mint(policy: AccessPolicy, recipient: Identity, sub_id: SubId, amount: u64) {
+        let _ = _guarded_mint(
+            policy,
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            storage
+                .max_supply,
+            recipient,
+            sub_id,
+            amount,
+        );
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn guarded_burn(policy: AccessPolicy, sub_id: SubId, amount: u64) {
+        _guarded_burn(policy, storage.total_supply, sub_id, amount);
+    }
+
+    #[storage(read, write)]
+    fn initialize_ownership(owner: Identity) {
+        initialize_ownership(owner);
+    }
+
+    #[storage(read, write)]
+    fn add_admin(admin: Identity) {
+        add_admin(admin);
+    }
+
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity) {
+        grant_role(role, account);
+    }
+
+    #[storage(write)]
+    fn pause() {
+        _pause();
+    }
+
+    #[storage(write)]
+    fn unpause() {
+        _unpause();
+    }
+}
+
+impl SupplyCheckpoints for Contract {
+    #[storage(read)]
+    fn total_supply_at(asset: AssetId, height: u32) -> Option<u64> {
+        _total_supply_at(storage.total_supply, asset, height)
+    }
+}
+
+impl BurnForwarded for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn burn_forwarded(sub_id: SubId) -> u64 {
+        _burn_forwarded(storage.total_supply, sub_id)
+    }
+}
+
+impl Allowance for Contract {
+    #[payable]
+    #[storage(read, write)]
+    fn deposit(recipient: Identity) -> u64 {
+        _deposit(storage.ledger_balances, recipient)
+    }
+
+    #[storage(read, write)]
+    fn withdraw(recipient: Identity, asset: AssetId, amount: u64) {
+        _withdraw(storage.ledger_balances, recipient, asset, amount);
+    }
+
+    #[storage(read)]
+    fn ledger_balance(owner: Identity, asset: AssetId) -> u64 {
+        _ledger_balance(storage.ledger_balances, owner, asset)
+    }
+
+    #[storage(write)]
+    fn approve(spender: Identity, asset: AssetId, amount: u64) {
+        _approve(storage.allowances, spender, asset, amount);
+    }
+
+    #[storage(read)]
+    fn allowance(owner: Identity, spender: Identity, asset: AssetId) -> u64 {
+        _allowance(storage.allowances, owner, spender, asset)
+    }
+
+    #[storage(read, write)]
+    fn transfer_from(owner: Identity, recipient: Identity, asset: AssetId, amount: u64) {
+        _transfer_from(
+            storage.ledger_balances,
+            storage.allowances,
+            owner,
+            recipient,
+            asset,
+            amount,
+        );
+    }
+}
+
+impl Batch for Contract {
+    #[storage(read, write)]
+    fn mint_batch(recipients: Vec<(Identity, SubId, u64)>) {
+        _mint_batch(
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            storage
+                .max_supply,
+            recipients,
+        );
+    }
+
+    #[storage(read, write)]
+    fn burn_batch(burns: Vec<(SubId, u64)>) {
+        _burn_batch(storage.total_supply, burns);
+    }
+}
+
+impl RemoveMetadata for Contract {
+    #[storage(read, write)]
+    fn remove_metadata(asset: AssetId, key: String) -> bool {
+        _remove_metadata(storage.metadata, asset, key)
+    }
+}
+
+impl MetadataSchemas for Contract {
+    #[storage(write)]
+    fn set_metadata_schema(key: String, schema: MetadataSchema) {
+        _set_metadata_schema(storage.metadata, key, schema);
+    }
+
+    #[storage(write)]
+    fn remove_metadata_schema(key: String) {
+        _remove_metadata_schema(storage.metadata, key);
+    }
+
+    #[storage(read)]
+    fn metadata_schema(key: String) -> Option<MetadataSchema> {
+        _metadata_schema(storage.metadata, key)
+    }
+}
+
+impl DefaultMetadata for Contract {
+    #[storage(read, write)]
+    fn set_default_metadata(key: String, metadata: Metadata) {
+        _set_default_metadata(storage.metadata, key, metadata);
+    }
+
+    #[storage(read)]
+    fn default_metadata(key: String) -> Option<Metadata> {
+        _default_metadata(storage.metadata, key)
+    }
+
+    #[storage(read)]
+    fn is_default_metadata(asset: AssetId, key: String) -> bool {
+        _is_default_metadata(storage.metadata, asset, key)
+    }
+}
+
+impl MetadataBatch for Contract {
+    #[storage(read, write)]
+    fn set_metadata_batch(entries: Vec<(AssetId, String, Metadata)>) {
+        _set_metadata_batch(storage.metadata, entries);
+    }
+
+    #[storage(read)]
+    fn metadata_batch(queries: Vec<(AssetId, String)>) -> Vec<Option<Metadata>> {
+        _metadata_batch(storage.metadata, queries)
+    }
+}
+
+impl MetadataKeys for Contract {
+    #[storage(read)]
+    fn metadata_keys_len(asset: AssetId) -> u64 {
+        _metadata_keys_len(storage.metadata, asset)
+    }
+
+    #[storage(read)]
+    fn metadata_key_at(asset: AssetId, index: u64) -> Option<String> {
+        _metadata_key_at(storage.metadata, asset, index)
+    }
+}
+
+impl FreezeMetadata for Contract {
+    #[storage(read, write)]
+    fn freeze_metadata(asset: AssetId) {
+        _freeze_metadata(storage.metadata, asset);
+    }
+
+    #[storage(read)]
+    fn is_metadata_frozen(asset: AssetId) -> bool {
+        _is_metadata_frozen(storage.metadata, asset)
+    }
+
+    #[storage(read, write)]
+    fn freeze_metadata_key(asset: AssetId, key: String) {
+        _freeze_metadata_key(storage.metadata, asset, key);
+    }
+
+    #[storage(read)]
+    fn is_metadata_key_frozen(asset: AssetId, key: String) -> bool {
+        _is_metadata_key_frozen(storage.metadata, asset, key)
+    }
+}
+
+impl Enumeration for Contract {
+    #[storage(read)]
+    fn asset_at(index: u64) -> Option<AssetId> {
+        _asset_at(storage.total_assets, index)
+    }
+
+    #[storage(read)]
+    fn sub_id_at(index: u64) -> Option<SubId> {
+        _sub_id_at(storage.total_assets, index)
+    }
+}
+
+impl Nft for Contract {
+    #[storage(read, write)]
+    fn mint_nft(recipient: Identity, sub_id: SubId) -> AssetId {
+        _mint_nft(
+            storage
+                .total_assets,
+            storage
+                .total_supply,
+            storage
+                .max_supply,
+            recipient,
+            sub_id,
+        )
+    }
+
+    #[payable]
+    #[storage(read, write)]
+    fn burn_nft(sub_id: SubId) {
+        _burn_nft(storage.total_supply, sub_id);
+    }
+
+    #[storage(read)]
+    fn is_nft_minted(asset: AssetId) -> bool {
+        _is_nft_minted(storage.total_supply, asset)
+    }
+
+    #[storage(read, write)]
+    fn set_token_uri(asset: AssetId, uri: String) {
+        _set_token_uri(storage.metadata, asset, uri);
+    }
+
+    #[storage(read)]
+    fn token_uri(asset: AssetId) -> Option<String> {
+        _token_uri(storage.metadata, asset)
+    }
+}
+
 #[test]
 fn test_total_assets() {
     let src3_abi = abi(SRC3, CONTRACT_ID);
@@ -0,0 +1,96 @@
+use crate::native_asset::tests::utils::{
+    interface::{allowance, approve},
+    setup::{defaults, setup, Approval},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn approves_spender() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_1).await,
+            0
+        );
+
+        let response = approve(&instance_1, other_identity, asset_id_1, 100).await;
+
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_1).await,
+            100
+        );
+        let log = response.decode_logs_with_type::<Approval>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            Approval {
+                owner: owner_identity,
+                spender: other_identity,
+                asset: asset_id_1,
+                amount: 100,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_allowance() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        approve(&instance_1, other_identity, asset_id_1, 100).await;
+        approve(&instance_1, other_identity, asset_id_1, 30).await;
+
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_1).await,
+            30
+        );
+    }
+
+    #[tokio::test]
+    async fn revokes_allowance() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        approve(&instance_1, other_identity, asset_id_1, 100).await;
+        approve(&instance_1, other_identity, asset_id_1, 0).await;
+
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_1).await,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn approves_per_asset_and_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        approve(&instance_1, other_identity, asset_id_1, 100).await;
+        approve(&instance_2, owner_identity, asset_id_2, 50).await;
+
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_1).await,
+            100
+        );
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_2).await,
+            0
+        );
+        assert_eq!(
+            allowance(&instance_1, other_identity, owner_identity, asset_id_2).await,
+            50
+        );
+        assert_eq!(
+            allowance(&instance_1, other_identity, owner_identity, asset_id_1).await,
+            0
+        );
+    }
+}
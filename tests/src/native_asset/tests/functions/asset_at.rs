@@ -0,0 +1,78 @@
+use crate::native_asset::tests::utils::{
+    interface::{asset_at, burn, mint, mint_batch, total_assets},
+    setup::{defaults, get_asset_id, setup},
+};
+use fuels::types::{Bits256, Bytes32};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_none_when_not_minted() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert_eq!(asset_at(&instance_1, 0).await, None);
+    }
+
+    #[tokio::test]
+    async fn enumerates_many_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let sub_ids: Vec<Bytes32> = (1..=10u8).map(|i| Bytes32::from([i; 32])).collect();
+
+        for sub_id in sub_ids.iter() {
+            mint(&instance_1, identity2.clone(), Some(Bits256(**sub_id)), 100).await;
+        }
+
+        assert_eq!(total_assets(&instance_1).await, sub_ids.len() as u64);
+        for (index, sub_id) in sub_ids.iter().enumerate() {
+            assert_eq!(
+                asset_at(&instance_1, index as u64).await,
+                Some(get_asset_id(*sub_id, id))
+            );
+        }
+        assert_eq!(asset_at(&instance_1, sub_ids.len() as u64).await, None);
+    }
+
+    #[tokio::test]
+    async fn enumerates_batch_minted_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, sub_id_2, identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, identity1.clone(), Some(sub_id_2), 100).await;
+        mint_batch(
+            &instance_1,
+            vec![
+                (identity1, sub_id_1, 100),
+                (identity2.clone(), sub_id_2, 100),
+                (identity2, sub_id_1, 100),
+            ],
+        )
+        .await;
+
+        assert_eq!(total_assets(&instance_1).await, 2);
+        assert_eq!(asset_at(&instance_1, 0).await, Some(asset_id_2));
+        assert_eq!(asset_at(&instance_1, 1).await, Some(asset_id_1));
+        assert_eq!(asset_at(&instance_1, 2).await, None);
+    }
+
+    #[tokio::test]
+    async fn does_not_duplicate_reminted_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, identity2.clone(), Some(sub_id_1), 100).await;
+        burn(&instance_2, asset_id_1, sub_id_1, 100).await;
+        mint(&instance_1, identity2, Some(sub_id_1), 100).await;
+
+        assert_eq!(total_assets(&instance_1).await, 1);
+        assert_eq!(asset_at(&instance_1, 0).await, Some(asset_id_1));
+        assert_eq!(asset_at(&instance_1, 1).await, None);
+    }
+}
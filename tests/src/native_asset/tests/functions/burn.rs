@@ -1,5 +1,5 @@
 use crate::native_asset::tests::utils::{
-    interface::{burn, mint, total_assets, total_supply},
+    interface::{burn, burn_forwarded, mint, total_assets, total_supply},
     setup::{defaults, get_wallet_balance, setup, TotalSupplyEvent},
 };
 use fuels::types::Identity;
@@ -294,6 +294,73 @@ mod success {
             }
         );
     }
+
+    #[tokio::test]
+    async fn burn_forwarded_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _owner_identity, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let mint_amount_1 = 100;
+        let burn_amount_1 = 25;
+
+        mint(&instance_1, identity2, Some(sub_id_1), mint_amount_1).await;
+
+        let response = burn_forwarded(&instance_2, asset_id_1, sub_id_1, burn_amount_1).await;
+
+        assert_eq!(response.value, burn_amount_1);
+        assert_eq!(
+            get_wallet_balance(&other_wallet, &asset_id_1).await,
+            mint_amount_1 - burn_amount_1
+        );
+        assert_eq!(
+            total_supply(&instance_1, asset_id_1).await,
+            Some(mint_amount_1 - burn_amount_1)
+        );
+        assert_eq!(total_assets(&instance_1).await, 1);
+        let log = response
+            .decode_logs_with_type::<TotalSupplyEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            TotalSupplyEvent {
+                asset: asset_id_1,
+                supply: mint_amount_1 - burn_amount_1,
+                sender: Identity::Address(other_wallet.address().into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn burn_forwarded_with_contract_balance() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _owner_identity, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let contract_identity = Identity::ContractId(id);
+        let mint_amount_1 = 100;
+        let contract_mint_amount = 50;
+        let burn_amount_1 = 25;
+
+        mint(&instance_1, identity2, Some(sub_id_1), mint_amount_1).await;
+        mint(
+            &instance_1,
+            contract_identity,
+            Some(sub_id_1),
+            contract_mint_amount,
+        )
+        .await;
+
+        burn_forwarded(&instance_2, asset_id_1, sub_id_1, burn_amount_1).await;
+
+        assert_eq!(
+            get_wallet_balance(&other_wallet, &asset_id_1).await,
+            mint_amount_1 - burn_amount_1
+        );
+        assert_eq!(
+            total_supply(&instance_1, asset_id_1).await,
+            Some(mint_amount_1 + contract_mint_amount - burn_amount_1)
+        );
+    }
 }
 
 mod revert {
@@ -423,4 +490,45 @@ mod revert {
 
         burn(&instance_2, asset_id_1, sub_id_1, burn_amount_1).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "IncorrectAsset")]
+    async fn when_forwarded_asset_does_not_match() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet.clone());
+        let mint_amount = 100;
+        let burn_amount = 50;
+
+        mint(&instance_1, identity2, Some(sub_id_1), mint_amount).await;
+
+        burn_forwarded(&instance_2, asset_id_1, sub_id_2, burn_amount).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "IncorrectAsset")]
+    async fn when_forwarded_asset_is_not_minted_by_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet.clone());
+        let mint_amount = 100;
+        let burn_amount = 50;
+
+        mint(&instance_1, identity2, Some(sub_id_1), mint_amount).await;
+
+        burn_forwarded(&instance_2, AssetId::zeroed(), sub_id_1, burn_amount).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroAmount")]
+    async fn when_burn_forwarded_zero() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet.clone());
+        let mint_amount = 100;
+
+        mint(&instance_1, identity2, Some(sub_id_1), mint_amount).await;
+
+        burn_forwarded(&instance_2, asset_id_1, sub_id_1, 0).await;
+    }
 }
@@ -0,0 +1,132 @@
+use crate::native_asset::tests::utils::{
+    interface::{burn_batch, mint_batch, total_assets, total_supply},
+    setup::{defaults, get_contract_balance, setup, TotalSupplyEvent},
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn burns_multiple_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let contract_identity = Identity::ContractId(id);
+        let mint_amount_1 = 100;
+        let mint_amount_2 = 200;
+
+        mint_batch(
+            &instance_1,
+            vec![
+                (contract_identity.clone(), sub_id_1, mint_amount_1),
+                (contract_identity, sub_id_2, mint_amount_2),
+            ],
+        )
+        .await;
+
+        let response = burn_batch(
+            &instance_1,
+            vec![
+                (sub_id_1, 25),
+                (sub_id_2, 50),
+                (sub_id_1, 25),
+                (sub_id_2, 100),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            get_contract_balance(&owner_wallet, id, &asset_id_1).await,
+            mint_amount_1 - 50
+        );
+        assert_eq!(
+            get_contract_balance(&owner_wallet, id, &asset_id_2).await,
+            mint_amount_2 - 150
+        );
+        assert_eq!(
+            total_supply(&instance_1, asset_id_1).await,
+            Some(mint_amount_1 - 50)
+        );
+        assert_eq!(
+            total_supply(&instance_1, asset_id_2).await,
+            Some(mint_amount_2 - 150)
+        );
+        assert_eq!(total_assets(&instance_1).await, 2);
+        let log = response
+            .decode_logs_with_type::<TotalSupplyEvent>()
+            .unwrap();
+        assert_eq!(
+            log,
+            vec![
+                TotalSupplyEvent {
+                    asset: asset_id_1,
+                    supply: mint_amount_1 - 50,
+                    sender: Identity::Address(owner_wallet.address().into()),
+                },
+                TotalSupplyEvent {
+                    asset: asset_id_2,
+                    supply: mint_amount_2 - 150,
+                    sender: Identity::Address(owner_wallet.address().into()),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn burns_to_zero() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet);
+        let mint_amount = 100;
+
+        mint_batch(
+            &instance_1,
+            vec![(Identity::ContractId(id), sub_id_1, mint_amount)],
+        )
+        .await;
+
+        burn_batch(
+            &instance_1,
+            vec![(sub_id_1, mint_amount / 2), (sub_id_1, mint_amount / 2)],
+        )
+        .await;
+
+        assert_eq!(
+            get_contract_balance(&owner_wallet, id, &asset_id_1).await,
+            0
+        );
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(0));
+        assert_eq!(total_assets(&instance_1).await, 1);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroAmount")]
+    async fn when_any_amount_is_zero() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint_batch(&instance_1, vec![(Identity::ContractId(id), sub_id_1, 100)]).await;
+
+        burn_batch(&instance_1, vec![(sub_id_1, 50), (sub_id_1, 0)]).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotEnoughCoins")]
+    async fn when_aggregate_exceeds_balance() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint_batch(&instance_1, vec![(Identity::ContractId(id), sub_id_1, 100)]).await;
+
+        burn_batch(&instance_1, vec![(sub_id_1, 60), (sub_id_1, 60)]).await;
+    }
+}
@@ -0,0 +1,63 @@
+use crate::native_asset::tests::utils::{
+    interface::{burn_nft, is_nft_minted, mint_nft, total_assets, total_supply},
+    setup::{defaults, get_wallet_balance, setup, TotalSupplyEvent},
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn burns_nft() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        mint_nft(&instance_1, identity2, sub_id_1).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 1);
+        assert!(is_nft_minted(&instance_1, asset_id_1).await);
+
+        let response = burn_nft(&instance_2, asset_id_1, sub_id_1).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 0);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(0));
+        assert_eq!(total_assets(&instance_1).await, 1);
+        assert!(!is_nft_minted(&instance_1, asset_id_1).await);
+        let log = response
+            .decode_logs_with_type::<TotalSupplyEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            TotalSupplyEvent {
+                asset: asset_id_1,
+                supply: 0,
+                sender: Identity::Address(other_wallet.address().into()),
+            }
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotEnoughCoins")]
+    async fn when_not_owned_by_contract() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint_nft(&instance_1, identity2, sub_id_1).await;
+
+        instance_1
+            .methods()
+            .burn_nft(sub_id_1)
+            .call()
+            .await
+            .unwrap();
+    }
+}
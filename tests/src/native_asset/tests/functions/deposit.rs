@@ -0,0 +1,109 @@
+use crate::native_asset::tests::utils::{
+    interface::{deposit, ledger_balance, mint},
+    setup::{defaults, get_contract_balance, get_wallet_balance, setup, LedgerDeposit},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn deposits_forwarded_coins() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let mint_amount = 100;
+        let deposit_amount = 40;
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), mint_amount).await;
+
+        assert_eq!(
+            ledger_balance(&instance_1, other_identity, asset_id_1).await,
+            0
+        );
+
+        let response = deposit(&instance_1, other_identity, asset_id_1, deposit_amount).await;
+
+        assert_eq!(response.value, deposit_amount);
+        assert_eq!(
+            ledger_balance(&instance_1, other_identity, asset_id_1).await,
+            deposit_amount
+        );
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_1).await,
+            0
+        );
+        assert_eq!(
+            get_wallet_balance(&owner_wallet, &asset_id_1).await,
+            mint_amount - deposit_amount
+        );
+        assert_eq!(
+            get_contract_balance(&owner_wallet, id, &asset_id_1).await,
+            deposit_amount
+        );
+        let log = response.decode_logs_with_type::<LedgerDeposit>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            LedgerDeposit {
+                recipient: other_identity,
+                asset: asset_id_1,
+                amount: deposit_amount,
+                sender: owner_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn deposits_twice() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 25).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 30).await;
+
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_1).await,
+            55
+        );
+    }
+
+    #[tokio::test]
+    async fn deposits_multiple_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        mint(&instance_1, owner_identity, Some(sub_id_2), 200).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 10).await;
+        deposit(&instance_1, owner_identity, asset_id_2, 20).await;
+
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_1).await,
+            10
+        );
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_2).await,
+            20
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroAmount")]
+    async fn when_no_coins_forwarded() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 0).await;
+    }
+}
@@ -0,0 +1,76 @@
+use crate::native_asset::tests::utils::{
+    interface::{freeze_metadata, is_metadata_frozen, is_metadata_key_frozen, name, set_name},
+    setup::{defaults, setup, MetadataFrozen},
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn freezes_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        assert!(!is_metadata_frozen(&instance_1, asset_id_1).await);
+        assert!(!is_metadata_key_frozen(&instance_1, asset_id_1, String::from("key1")).await);
+
+        let response = freeze_metadata(&instance_1, asset_id_1).await;
+
+        assert!(is_metadata_frozen(&instance_1, asset_id_1).await);
+        assert!(is_metadata_key_frozen(&instance_1, asset_id_1, String::from("key1")).await);
+        let log = response.decode_logs_with_type::<MetadataFrozen>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            MetadataFrozen {
+                asset: asset_id_1,
+                key: None,
+                sender: Identity::Address(owner_wallet.address().into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_existing_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let name_1 = String::from("Fuel Asset 1");
+
+        set_name(&instance_1, asset_id_1, Some(name_1.clone())).await;
+        freeze_metadata(&instance_1, asset_id_1).await;
+
+        assert_eq!(name(&instance_1, asset_id_1).await, Some(name_1));
+    }
+
+    #[tokio::test]
+    async fn does_not_freeze_other_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let name_2 = String::from("Fuel Asset 2");
+
+        freeze_metadata(&instance_1, asset_id_1).await;
+
+        assert!(!is_metadata_frozen(&instance_1, asset_id_2).await);
+        set_name(&instance_1, asset_id_2, Some(name_2.clone())).await;
+        assert_eq!(name(&instance_1, asset_id_2).await, Some(name_2));
+    }
+
+    #[tokio::test]
+    async fn freezes_twice() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        freeze_metadata(&instance_1, asset_id_1).await;
+        let response = freeze_metadata(&instance_1, asset_id_1).await;
+
+        assert!(is_metadata_frozen(&instance_1, asset_id_1).await);
+        let log = response.decode_logs_with_type::<MetadataFrozen>().unwrap();
+        assert!(log.is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::native_asset::tests::utils::{
+    interface::{
+        freeze_metadata_key, is_metadata_frozen, is_metadata_key_frozen, metadata, set_metadata,
+    },
+    setup::{defaults, setup, Metadata, MetadataFrozen},
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn freezes_metadata_key() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let key = String::from("key1");
+
+        assert!(!is_metadata_key_frozen(&instance_1, asset_id_1, key.clone()).await);
+
+        let response = freeze_metadata_key(&instance_1, asset_id_1, key.clone()).await;
+
+        assert!(is_metadata_key_frozen(&instance_1, asset_id_1, key.clone()).await);
+        assert!(!is_metadata_frozen(&instance_1, asset_id_1).await);
+        let log = response.decode_logs_with_type::<MetadataFrozen>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            MetadataFrozen {
+                asset: asset_id_1,
+                key: Some(key),
+                sender: Identity::Address(owner_wallet.address().into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_freeze_other_keys_or_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key1 = String::from("key1");
+        let key2 = String::from("key2");
+        let metadata1 = Metadata::Int(1);
+
+        freeze_metadata_key(&instance_1, asset_id_1, key1.clone()).await;
+
+        assert!(!is_metadata_key_frozen(&instance_1, asset_id_1, key2.clone()).await);
+        assert!(!is_metadata_key_frozen(&instance_1, asset_id_2, key1.clone()).await);
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key2.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_2,
+            key1.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key2).await,
+            Some(metadata1.clone())
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, key1).await,
+            Some(metadata1)
+        );
+    }
+
+    #[tokio::test]
+    async fn freezes_twice() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        freeze_metadata_key(&instance_1, asset_id_1, key.clone()).await;
+        let response = freeze_metadata_key(&instance_1, asset_id_1, key.clone()).await;
+
+        assert!(is_metadata_key_frozen(&instance_1, asset_id_1, key).await);
+        let log = response.decode_logs_with_type::<MetadataFrozen>().unwrap();
+        assert!(log.is_empty());
+    }
+}
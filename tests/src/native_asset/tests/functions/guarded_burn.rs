@@ -0,0 +1,123 @@
+use crate::native_asset::tests::utils::{
+    interface::{
+        add_admin, grant_role, guarded_burn, initialize_ownership, mint, pause, total_supply,
+    },
+    setup::{defaults, get_wallet_balance, setup, AccessPolicy, MINTER_ROLE},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn burns_as_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet);
+
+        initialize_ownership(&instance_1, owner_identity).await;
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        guarded_burn(&instance_1, AccessPolicy::Owner, asset_id_1, sub_id_1, 25).await;
+
+        assert_eq!(get_wallet_balance(&owner_wallet, &asset_id_1).await, 75);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(75));
+    }
+
+    #[tokio::test]
+    async fn burns_as_admin() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet.clone());
+
+        initialize_ownership(&instance_1, owner_identity).await;
+        add_admin(&instance_1, other_identity).await;
+        mint(&instance_1, other_identity, Some(sub_id_1), 100).await;
+        guarded_burn(&instance_2, AccessPolicy::Admin, asset_id_1, sub_id_1, 25).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 75);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(75));
+    }
+
+    #[tokio::test]
+    async fn burns_with_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet.clone());
+
+        initialize_ownership(&instance_1, owner_identity).await;
+        grant_role(&instance_1, MINTER_ROLE, other_identity).await;
+        mint(&instance_1, other_identity, Some(sub_id_1), 100).await;
+        guarded_burn(
+            &instance_2,
+            AccessPolicy::Role(MINTER_ROLE),
+            asset_id_1,
+            sub_id_1,
+            25,
+        )
+        .await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 75);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(75));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        initialize_ownership(&instance_1, owner_identity).await;
+        mint(&instance_1, other_identity, Some(sub_id_1), 100).await;
+        guarded_burn(&instance_2, AccessPolicy::Owner, asset_id_1, sub_id_1, 25).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotAdmin")]
+    async fn when_not_admin() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        initialize_ownership(&instance_1, owner_identity).await;
+        mint(&instance_1, other_identity, Some(sub_id_1), 100).await;
+        guarded_burn(&instance_2, AccessPolicy::Admin, asset_id_1, sub_id_1, 25).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_missing_role() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        initialize_ownership(&instance_1, owner_identity).await;
+        mint(&instance_1, other_identity, Some(sub_id_1), 100).await;
+        guarded_burn(
+            &instance_2,
+            AccessPolicy::Role(MINTER_ROLE),
+            asset_id_1,
+            sub_id_1,
+            25,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Paused")]
+    async fn when_paused() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        initialize_ownership(&instance_1, owner_identity).await;
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        pause(&instance_1).await;
+        guarded_burn(&instance_1, AccessPolicy::Owner, asset_id_1, sub_id_1, 25).await;
+    }
+}
@@ -0,0 +1,44 @@
+use crate::native_asset::tests::utils::{
+    interface::{is_default_metadata, set_default_metadata, set_metadata},
+    setup::{defaults, setup, Metadata},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn false_when_unset() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert!(!is_default_metadata(&instance_1, asset_id_1, String::from("key1")).await);
+    }
+
+    #[tokio::test]
+    async fn false_when_only_asset_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata(&instance_1, asset_id_1, key.clone(), Some(Metadata::Int(1))).await;
+
+        assert!(!is_default_metadata(&instance_1, asset_id_1, key).await);
+    }
+
+    #[tokio::test]
+    async fn true_when_only_default_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(1)).await;
+
+        assert!(is_default_metadata(&instance_1, asset_id_1, key.clone()).await);
+        assert!(is_default_metadata(&instance_1, asset_id_2, key.clone()).await);
+        assert!(!is_default_metadata(&instance_1, asset_id_1, String::from("key2")).await);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::native_asset::tests::utils::{
+    interface::{is_nft_minted, mint, mint_nft},
+    setup::{defaults, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn one_nft() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert!(!is_nft_minted(&instance_1, asset_id_1).await);
+
+        mint_nft(&instance_1, identity2, sub_id_1).await;
+
+        assert!(is_nft_minted(&instance_1, asset_id_1).await);
+    }
+
+    #[tokio::test]
+    async fn only_minted_nfts() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint_nft(&instance_1, identity2, sub_id_1).await;
+
+        assert!(is_nft_minted(&instance_1, asset_id_1).await);
+        assert!(!is_nft_minted(&instance_1, asset_id_2).await);
+    }
+
+    #[tokio::test]
+    async fn fungible_asset() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, identity2, Some(sub_id_1), 100).await;
+
+        assert!(!is_nft_minted(&instance_1, asset_id_1).await);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::native_asset::tests::utils::{
+    interface::{max_supply, set_max_supply},
+    setup::{defaults, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_no_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, None);
+    }
+
+    #[tokio::test]
+    async fn gets_one_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let cap = 1_000;
+
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, Some(cap));
+        assert_eq!(max_supply(&instance_1, asset_id_2).await, None);
+    }
+
+    #[tokio::test]
+    async fn gets_multiple_max_supplies() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let cap_1 = 1_000;
+        let cap_2 = 2_000;
+
+        set_max_supply(&instance_1, asset_id_1, cap_1).await;
+        set_max_supply(&instance_1, asset_id_2, cap_2).await;
+
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, Some(cap_1));
+        assert_eq!(max_supply(&instance_1, asset_id_2).await, Some(cap_2));
+    }
+}
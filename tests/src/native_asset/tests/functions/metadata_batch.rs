@@ -0,0 +1,102 @@
+use crate::native_asset::tests::utils::{
+    interface::{metadata_batch, set_metadata},
+    setup::{defaults, setup, Metadata},
+};
+use fuels::types::{Bits256, Bytes};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_none_when_unset() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        let results = metadata_batch(
+            &instance_1,
+            vec![
+                (asset_id_1, String::from("key1")),
+                (asset_id_2, String::from("key1")),
+            ],
+        )
+        .await;
+
+        assert_eq!(results, vec![None, None]);
+    }
+
+    #[tokio::test]
+    async fn gets_many_entries_in_order() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let metadata1 = Metadata::String(String::from("Fuel NFT Metadata 1"));
+        let metadata2 = Metadata::Int(1);
+        let metadata3 = Metadata::Bytes(Bytes(vec![1u8; 32]));
+        let metadata4 = Metadata::B256(Bits256([1u8; 32]));
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            String::from("key1"),
+            Some(metadata1.clone()),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            String::from("key2"),
+            Some(metadata2.clone()),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_2,
+            String::from("key1"),
+            Some(metadata3.clone()),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_2,
+            String::from("key2"),
+            Some(metadata4.clone()),
+        )
+        .await;
+
+        let results = metadata_batch(
+            &instance_1,
+            vec![
+                (asset_id_2, String::from("key2")),
+                (asset_id_1, String::from("key1")),
+                (asset_id_1, String::from("key3")),
+                (asset_id_2, String::from("key1")),
+                (asset_id_1, String::from("key2")),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            results,
+            vec![
+                Some(metadata4),
+                Some(metadata1),
+                None,
+                Some(metadata3),
+                Some(metadata2),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn gets_empty_batch() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        let results = metadata_batch(&instance_1, vec![]).await;
+
+        assert!(results.is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+use crate::native_asset::tests::utils::{
+    interface::{metadata, metadata_key_at, metadata_keys_len, remove_metadata, set_metadata},
+    setup::{defaults, setup, Metadata},
+};
+use fuels::types::{Bits256, Bytes};
+
+fn metadata_variant(index: usize) -> Metadata {
+    match index % 4 {
+        0 => Metadata::String(format!("Fuel NFT Metadata {}", index)),
+        1 => Metadata::Int(index as u64),
+        2 => Metadata::Bytes(Bytes(vec![index as u8; 32])),
+        _ => Metadata::B256(Bits256([index as u8; 32])),
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_none_when_unset() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert_eq!(metadata_key_at(&instance_1, asset_id_1, 0).await, None);
+    }
+
+    #[tokio::test]
+    async fn enumerates_many_keys() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let keys: Vec<String> = (0..12).map(|index| format!("key{}", index)).collect();
+
+        for (index, key) in keys.iter().enumerate() {
+            set_metadata(
+                &instance_1,
+                asset_id_1,
+                key.clone(),
+                Some(metadata_variant(index)),
+            )
+            .await;
+        }
+
+        assert_eq!(
+            metadata_keys_len(&instance_1, asset_id_1).await,
+            keys.len() as u64
+        );
+        for (index, key) in keys.iter().enumerate() {
+            let enumerated_key = metadata_key_at(&instance_1, asset_id_1, index as u64)
+                .await
+                .unwrap();
+            assert_eq!(enumerated_key, *key);
+            assert_eq!(
+                metadata(&instance_1, asset_id_1, enumerated_key).await,
+                Some(metadata_variant(index))
+            );
+        }
+        assert_eq!(
+            metadata_key_at(&instance_1, asset_id_1, keys.len() as u64).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn moves_last_key_on_remove() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key1 = String::from("key1");
+        let key2 = String::from("key2");
+        let key3 = String::from("key3");
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key1.clone(),
+            Some(metadata_variant(0)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key2.clone(),
+            Some(metadata_variant(1)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key3.clone(),
+            Some(metadata_variant(2)),
+        )
+        .await;
+
+        remove_metadata(&instance_1, asset_id_1, key1).await;
+
+        assert_eq!(
+            metadata_key_at(&instance_1, asset_id_1, 0).await,
+            Some(key3.clone())
+        );
+        assert_eq!(
+            metadata_key_at(&instance_1, asset_id_1, 1).await,
+            Some(key2.clone())
+        );
+        assert_eq!(metadata_key_at(&instance_1, asset_id_1, 2).await, None);
+
+        // The moved key must still be removable from its new position.
+        remove_metadata(&instance_1, asset_id_1, key3).await;
+
+        assert_eq!(
+            metadata_key_at(&instance_1, asset_id_1, 0).await,
+            Some(key2)
+        );
+        assert_eq!(metadata_key_at(&instance_1, asset_id_1, 1).await, None);
+    }
+
+    #[tokio::test]
+    async fn readds_removed_key() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key1 = String::from("key1");
+        let key2 = String::from("key2");
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key1.clone(),
+            Some(metadata_variant(0)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key2.clone(),
+            Some(metadata_variant(1)),
+        )
+        .await;
+        remove_metadata(&instance_1, asset_id_1, key1.clone()).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key1.clone(),
+            Some(metadata_variant(3)),
+        )
+        .await;
+
+        assert_eq!(metadata_keys_len(&instance_1, asset_id_1).await, 2);
+        assert_eq!(
+            metadata_key_at(&instance_1, asset_id_1, 0).await,
+            Some(key2)
+        );
+        assert_eq!(
+            metadata_key_at(&instance_1, asset_id_1, 1).await,
+            Some(key1)
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::native_asset::tests::utils::{
+    interface::{metadata_keys_len, remove_metadata, set_metadata},
+    setup::{defaults, setup, Metadata},
+};
+use fuels::types::{Bits256, Bytes};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_zero_when_unset() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert_eq!(metadata_keys_len(&instance_1, asset_id_1).await, 0);
+    }
+
+    #[tokio::test]
+    async fn counts_every_metadata_type() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let metadata_variants = vec![
+            Metadata::String(String::from("Fuel NFT Metadata")),
+            Metadata::Int(1),
+            Metadata::Bytes(
+                Bytes::from_hex_str(
+                    "0101010101010101010101010101010101010101010101010101010101010101",
+                )
+                .expect("failed to convert to bytes"),
+            ),
+            Metadata::B256(Bits256([1u8; 32])),
+        ];
+
+        for (index, metadata1) in metadata_variants.iter().enumerate() {
+            set_metadata(
+                &instance_1,
+                asset_id_1,
+                format!("key{}", index),
+                Some(metadata1.clone()),
+            )
+            .await;
+            assert_eq!(
+                metadata_keys_len(&instance_1, asset_id_1).await,
+                index as u64 + 1
+            );
+        }
+
+        assert_eq!(metadata_keys_len(&instance_1, asset_id_2).await, 0);
+    }
+
+    #[tokio::test]
+    async fn does_not_count_overwritten_key() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata(&instance_1, asset_id_1, key.clone(), Some(Metadata::Int(1))).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::String(String::from("Fuel NFT Metadata"))),
+        )
+        .await;
+
+        assert_eq!(metadata_keys_len(&instance_1, asset_id_1).await, 1);
+    }
+
+    #[tokio::test]
+    async fn decrements_on_remove() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key1 = String::from("key1");
+        let key2 = String::from("key2");
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key1.clone(),
+            Some(Metadata::Int(1)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key2.clone(),
+            Some(Metadata::Int(2)),
+        )
+        .await;
+        assert_eq!(metadata_keys_len(&instance_1, asset_id_1).await, 2);
+
+        remove_metadata(&instance_1, asset_id_1, key1.clone()).await;
+        assert_eq!(metadata_keys_len(&instance_1, asset_id_1).await, 1);
+
+        remove_metadata(&instance_1, asset_id_1, key1).await;
+        assert_eq!(metadata_keys_len(&instance_1, asset_id_1).await, 1);
+
+        remove_metadata(&instance_1, asset_id_1, key2).await;
+        assert_eq!(metadata_keys_len(&instance_1, asset_id_1).await, 0);
+    }
+}
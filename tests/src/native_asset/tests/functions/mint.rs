@@ -1,5 +1,5 @@
 use crate::native_asset::tests::utils::{
-    interface::{mint, total_assets, total_supply},
+    interface::{mint, set_max_supply, total_assets, total_supply},
     setup::{defaults, get_asset_id, get_wallet_balance, setup, TotalSupplyEvent},
 };
 use fuels::types::{Bytes32, Identity};
@@ -242,6 +242,62 @@ mod success {
             }
         );
     }
+
+    #[tokio::test]
+    async fn mints_up_to_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let cap = 300;
+        let mint_amount_1 = 100;
+        let mint_amount_2 = 200;
+
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+
+        mint(
+            &instance_1,
+            identity2.clone(),
+            Some(sub_id_1),
+            mint_amount_1,
+        )
+        .await;
+        let response = mint(&instance_1, identity2, Some(sub_id_1), mint_amount_2).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, cap);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(cap));
+        let log = response
+            .decode_logs_with_type::<TotalSupplyEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            TotalSupplyEvent {
+                asset: asset_id_1,
+                supply: cap,
+                sender: Identity::Address(owner_wallet.address().into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn mints_other_asset_when_max_supply_reached() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let cap = 100;
+        let mint_amount = 200;
+
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+        mint(&instance_1, identity2.clone(), Some(sub_id_1), cap).await;
+
+        mint(&instance_1, identity2, Some(sub_id_2), mint_amount).await;
+
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(cap));
+        assert_eq!(
+            total_supply(&instance_1, asset_id_2).await,
+            Some(mint_amount)
+        );
+    }
 }
 
 mod revert {
@@ -258,4 +314,44 @@ mod revert {
 
         mint(&instance_1, identity2, Some(sub_id_1), mint_amount).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxSupplyExceeded")]
+    async fn mints_more_than_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let cap = 100;
+
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+
+        mint(&instance_1, identity2, Some(sub_id_1), cap + 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxSupplyExceeded")]
+    async fn mints_past_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let cap = 100;
+
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+        mint(&instance_1, identity2.clone(), Some(sub_id_1), cap).await;
+
+        mint(&instance_1, identity2, Some(sub_id_1), 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxSupplyExceeded")]
+    async fn mints_when_max_supply_below_current_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, identity2.clone(), Some(sub_id_1), 100).await;
+        set_max_supply(&instance_1, asset_id_1, 50).await;
+
+        mint(&instance_1, identity2, Some(sub_id_1), 1).await;
+    }
 }
@@ -0,0 +1,180 @@
+use crate::native_asset::tests::utils::{
+    interface::{mint_batch, set_max_supply, total_assets, total_supply},
+    setup::{defaults, get_wallet_balance, recipient_wallets, setup, TotalSupplyEvent},
+};
+use fuels::types::{Address, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mints_to_many_recipients() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let recipients = recipient_wallets(&owner_wallet, 10);
+        let mint_amount = 100;
+
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, None);
+        assert_eq!(total_assets(&instance_1).await, 0);
+
+        let batch = recipients
+            .iter()
+            .map(|wallet| {
+                (
+                    Identity::Address(Address::from(wallet.address())),
+                    sub_id_1,
+                    mint_amount,
+                )
+            })
+            .collect();
+        let response = mint_batch(&instance_1, batch).await;
+
+        for wallet in recipients.iter() {
+            assert_eq!(get_wallet_balance(wallet, &asset_id_1).await, mint_amount);
+        }
+        assert_eq!(
+            total_supply(&instance_1, asset_id_1).await,
+            Some(mint_amount * 10)
+        );
+        assert_eq!(total_assets(&instance_1).await, 1);
+        let log = response
+            .decode_logs_with_type::<TotalSupplyEvent>()
+            .unwrap();
+        assert_eq!(log.len(), 1);
+        assert_eq!(
+            *log.first().unwrap(),
+            TotalSupplyEvent {
+                asset: asset_id_1,
+                supply: mint_amount * 10,
+                sender: Identity::Address(owner_wallet.address().into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn mints_multiple_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, sub_id_2, identity1, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let mint_amount_1 = 100;
+        let mint_amount_2 = 200;
+
+        let batch = vec![
+            (identity1.clone(), sub_id_1, mint_amount_1),
+            (identity2.clone(), sub_id_2, mint_amount_2),
+            (identity2, sub_id_1, mint_amount_1),
+            (identity1, sub_id_2, mint_amount_2),
+        ];
+        let response = mint_batch(&instance_1, batch).await;
+
+        assert_eq!(
+            get_wallet_balance(&owner_wallet, &asset_id_1).await,
+            mint_amount_1
+        );
+        assert_eq!(
+            get_wallet_balance(&owner_wallet, &asset_id_2).await,
+            mint_amount_2
+        );
+        assert_eq!(
+            get_wallet_balance(&other_wallet, &asset_id_1).await,
+            mint_amount_1
+        );
+        assert_eq!(
+            get_wallet_balance(&other_wallet, &asset_id_2).await,
+            mint_amount_2
+        );
+        assert_eq!(
+            total_supply(&instance_1, asset_id_1).await,
+            Some(mint_amount_1 * 2)
+        );
+        assert_eq!(
+            total_supply(&instance_1, asset_id_2).await,
+            Some(mint_amount_2 * 2)
+        );
+        assert_eq!(total_assets(&instance_1).await, 2);
+        let log = response
+            .decode_logs_with_type::<TotalSupplyEvent>()
+            .unwrap();
+        assert_eq!(
+            log,
+            vec![
+                TotalSupplyEvent {
+                    asset: asset_id_1,
+                    supply: mint_amount_1 * 2,
+                    sender: Identity::Address(owner_wallet.address().into()),
+                },
+                TotalSupplyEvent {
+                    asset: asset_id_2,
+                    supply: mint_amount_2 * 2,
+                    sender: Identity::Address(owner_wallet.address().into()),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn mints_up_to_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, identity1, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let cap = 100;
+
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+
+        let batch = vec![
+            (identity1, sub_id_1, cap / 2),
+            (identity2, sub_id_1, cap / 2),
+        ];
+        mint_batch(&instance_1, batch).await;
+
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(cap));
+    }
+
+    #[tokio::test]
+    async fn mints_empty_batch() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        let response = mint_batch(&instance_1, vec![]).await;
+
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, None);
+        assert_eq!(total_assets(&instance_1).await, 0);
+        let log = response
+            .decode_logs_with_type::<TotalSupplyEvent>()
+            .unwrap();
+        assert!(log.is_empty());
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroAmount")]
+    async fn when_any_amount_is_zero() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        let batch = vec![(identity1, sub_id_1, 100), (identity2, sub_id_1, 0)];
+        mint_batch(&instance_1, batch).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxSupplyExceeded")]
+    async fn when_aggregate_exceeds_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let cap = 100;
+
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+
+        let batch = vec![(identity1, sub_id_1, cap), (identity2, sub_id_1, 1)];
+        mint_batch(&instance_1, batch).await;
+    }
+}
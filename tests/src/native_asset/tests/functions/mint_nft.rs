@@ -0,0 +1,115 @@
+use crate::native_asset::tests::utils::{
+    interface::{burn_nft, is_nft_minted, max_supply, mint, mint_nft, total_assets, total_supply},
+    setup::{defaults, get_wallet_balance, setup, TotalSupplyEvent},
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mints_nft() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 0);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, None);
+        assert_eq!(total_assets(&instance_1).await, 0);
+        assert!(!is_nft_minted(&instance_1, asset_id_1).await);
+
+        let response = mint_nft(&instance_1, identity2, sub_id_1).await;
+
+        assert_eq!(response.value, asset_id_1);
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 1);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(1));
+        assert_eq!(total_assets(&instance_1).await, 1);
+        assert!(is_nft_minted(&instance_1, asset_id_1).await);
+        let log = response
+            .decode_logs_with_type::<TotalSupplyEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            TotalSupplyEvent {
+                asset: asset_id_1,
+                supply: 1,
+                sender: Identity::Address(owner_wallet.address().into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn mints_multiple_nfts() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, sub_id_2, identity1, identity2) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+
+        mint_nft(&instance_1, identity1, sub_id_1).await;
+        mint_nft(&instance_1, identity2, sub_id_2).await;
+
+        assert_eq!(get_wallet_balance(&owner_wallet, &asset_id_1).await, 1);
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_2).await, 1);
+        assert_eq!(total_supply(&instance_1, asset_id_1).await, Some(1));
+        assert_eq!(total_supply(&instance_1, asset_id_2).await, Some(1));
+        assert_eq!(total_assets(&instance_1).await, 2);
+        assert!(is_nft_minted(&instance_1, asset_id_1).await);
+        assert!(is_nft_minted(&instance_1, asset_id_2).await);
+    }
+
+    #[tokio::test]
+    async fn caps_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, None);
+
+        mint_nft(&instance_1, identity2, sub_id_1).await;
+
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, Some(1));
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyMinted")]
+    async fn when_already_minted() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint_nft(&instance_1, identity2, sub_id_1).await;
+
+        mint_nft(&instance_1, identity1, sub_id_1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "AlreadyMinted")]
+    async fn when_already_burned() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint_nft(&instance_1, identity2.clone(), sub_id_1).await;
+        burn_nft(&instance_2, asset_id_1, sub_id_1).await;
+
+        mint_nft(&instance_1, identity2, sub_id_1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxSupplyExceeded")]
+    async fn when_minted_again_with_mint() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint_nft(&instance_1, identity2, sub_id_1).await;
+
+        mint(&instance_1, identity1, Some(sub_id_1), 1).await;
+    }
+}
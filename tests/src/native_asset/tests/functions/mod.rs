@@ -1,12 +1,39 @@
+mod approve;
+mod asset_at;
 mod burn;
+mod burn_batch;
+mod burn_nft;
 mod decimals;
+mod deposit;
+mod freeze_metadata;
+mod freeze_metadata_key;
+mod guarded_burn;
+mod guarded_mint;
+mod is_default_metadata;
+mod is_nft_minted;
+mod max_supply;
 mod metadata;
+mod metadata_batch;
+mod metadata_key_at;
+mod metadata_keys_len;
 mod mint;
+mod mint_batch;
+mod mint_nft;
 mod name;
 mod set_decimals;
+mod set_default_metadata;
+mod set_max_supply;
 mod set_metadata;
+mod set_metadata_batch;
+mod set_metadata_schema;
 mod set_name;
 mod set_symbol;
+mod sub_id;
+mod sub_id_at;
 mod symbol;
+mod token_uri;
 mod total_assets;
 mod total_supply;
+mod total_supply_at;
+mod transfer_from;
+mod withdraw;
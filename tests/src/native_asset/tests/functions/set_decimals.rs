@@ -1,5 +1,5 @@
 use crate::native_asset::tests::utils::{
-    interface::{decimals, set_decimals},
+    interface::{decimals, freeze_metadata, set_decimals},
     setup::{defaults, get_asset_id, setup, SetDecimalsEvent},
 };
 use fuels::types::{Bytes32, Identity};
@@ -210,3 +210,21 @@ mod success {
         assert_eq!(decimals(&instance_1, asset_id_2).await, Some(decimals_2));
     }
 }
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "Frozen")]
+    async fn when_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let decimals_1 = 7u8;
+
+        freeze_metadata(&instance_1, asset_id_1).await;
+
+        set_decimals(&instance_1, asset_id_1, decimals_1).await;
+    }
+}
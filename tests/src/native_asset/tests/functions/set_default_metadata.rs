@@ -0,0 +1,252 @@
+use crate::native_asset::tests::utils::{
+    interface::{
+        default_metadata, freeze_metadata, freeze_metadata_key, is_default_metadata, metadata,
+        remove_metadata, set_default_metadata, set_metadata,
+    },
+    setup::{defaults, setup, Metadata, SetDefaultMetadata},
+};
+use fuels::types::{Bits256, Bytes, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_default_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let metadata1 = Metadata::String(String::from("Fuel Collection"));
+        let key = String::from("collection");
+
+        assert_eq!(default_metadata(&instance_1, key.clone()).await, None);
+        assert_eq!(metadata(&instance_1, asset_id_1, key.clone()).await, None);
+
+        let response = set_default_metadata(&instance_1, key.clone(), metadata1.clone()).await;
+
+        assert_eq!(
+            default_metadata(&instance_1, key.clone()).await,
+            Some(metadata1.clone())
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key.clone()).await,
+            Some(metadata1.clone())
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, key.clone()).await,
+            Some(metadata1.clone())
+        );
+        let log = response
+            .decode_logs_with_type::<SetDefaultMetadata>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            SetDefaultMetadata {
+                key,
+                metadata: metadata1,
+                sender: Identity::Address(owner_wallet.address().into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_every_metadata_type() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let metadata_and_keys = vec![
+            (
+                Metadata::String(String::from("Fuel Collection")),
+                String::from("key1"),
+            ),
+            (Metadata::Int(1), String::from("key2")),
+            (Metadata::Bytes(Bytes(vec![1u8; 32])), String::from("key3")),
+            (Metadata::B256(Bits256([1u8; 32])), String::from("key4")),
+        ];
+
+        for (metadata1, key) in metadata_and_keys.iter() {
+            set_default_metadata(&instance_1, key.clone(), metadata1.clone()).await;
+        }
+
+        for (metadata1, key) in metadata_and_keys.iter() {
+            assert_eq!(
+                metadata(&instance_1, asset_id_1, key.clone()).await,
+                Some(metadata1.clone())
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn asset_metadata_overrides_default() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let default = Metadata::Int(500);
+        let override_metadata = Metadata::Int(250);
+        let key = String::from("royalty");
+
+        set_default_metadata(&instance_1, key.clone(), default.clone()).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(override_metadata.clone()),
+        )
+        .await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key.clone()).await,
+            Some(override_metadata)
+        );
+        assert!(!is_default_metadata(&instance_1, asset_id_1, key.clone()).await);
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, key.clone()).await,
+            Some(default.clone())
+        );
+        assert!(is_default_metadata(&instance_1, asset_id_2, key.clone()).await);
+
+        remove_metadata(&instance_1, asset_id_1, key.clone()).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key.clone()).await,
+            Some(default)
+        );
+        assert!(is_default_metadata(&instance_1, asset_id_1, key).await);
+    }
+
+    #[tokio::test]
+    async fn overwrites_default_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("royalty");
+
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(500)).await;
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(100)).await;
+
+        assert_eq!(
+            default_metadata(&instance_1, key.clone()).await,
+            Some(Metadata::Int(100))
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(Metadata::Int(100))
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_apply_to_frozen_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("royalty");
+        let other_key = String::from("collection");
+
+        freeze_metadata(&instance_1, asset_id_1).await;
+        freeze_metadata_key(&instance_1, asset_id_2, key.clone()).await;
+
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(500)).await;
+        set_default_metadata(
+            &instance_1,
+            other_key.clone(),
+            Metadata::String(String::from("Fuel Collection")),
+        )
+        .await;
+
+        assert_eq!(metadata(&instance_1, asset_id_1, key.clone()).await, None);
+        assert!(!is_default_metadata(&instance_1, asset_id_1, key.clone()).await);
+        assert_eq!(metadata(&instance_1, asset_id_2, key.clone()).await, None);
+        assert!(!is_default_metadata(&instance_1, asset_id_2, key).await);
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, other_key.clone()).await,
+            Some(Metadata::String(String::from("Fuel Collection")))
+        );
+        assert!(is_default_metadata(&instance_1, asset_id_2, other_key).await);
+    }
+
+    #[tokio::test]
+    async fn keeps_default_of_metadata_frozen_after_default() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("royalty");
+
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(500)).await;
+        freeze_metadata(&instance_1, asset_id_1).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key.clone()).await,
+            Some(Metadata::Int(500))
+        );
+
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(1000)).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key.clone()).await,
+            Some(Metadata::Int(500))
+        );
+        assert!(!is_default_metadata(&instance_1, asset_id_1, key.clone()).await);
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, key.clone()).await,
+            Some(Metadata::Int(1000))
+        );
+        assert!(is_default_metadata(&instance_1, asset_id_2, key).await);
+    }
+
+    #[tokio::test]
+    async fn keeps_default_of_key_frozen_after_default() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("royalty");
+
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(500)).await;
+        freeze_metadata_key(&instance_1, asset_id_1, key.clone()).await;
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(1000)).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key.clone()).await,
+            Some(Metadata::Int(500))
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, key.clone()).await,
+            Some(Metadata::Int(1000))
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "EmptyString")]
+    async fn when_empty_string() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        set_default_metadata(
+            &instance_1,
+            String::from("collection"),
+            Metadata::String(String::from("")),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "EmptyBytes")]
+    async fn when_empty_bytes() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        set_default_metadata(
+            &instance_1,
+            String::from("collection"),
+            Metadata::Bytes(Bytes(vec![])),
+        )
+        .await;
+    }
+}
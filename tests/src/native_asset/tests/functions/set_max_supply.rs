@@ -0,0 +1,56 @@
+use crate::native_asset::tests::utils::{
+    interface::{max_supply, mint, set_max_supply, total_supply},
+    setup::{defaults, setup},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_max_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let cap = 1_000;
+
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, None);
+
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, Some(cap));
+    }
+
+    #[tokio::test]
+    async fn sets_max_supply_twice() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let cap_1 = 1_000;
+        let cap_2 = 500;
+
+        set_max_supply(&instance_1, asset_id_1, cap_1).await;
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, Some(cap_1));
+
+        set_max_supply(&instance_1, asset_id_1, cap_2).await;
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, Some(cap_2));
+    }
+
+    #[tokio::test]
+    async fn sets_max_supply_below_current_supply() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let mint_amount = 100;
+        let cap = 50;
+
+        mint(&instance_1, identity2, Some(sub_id_1), mint_amount).await;
+        set_max_supply(&instance_1, asset_id_1, cap).await;
+
+        assert_eq!(max_supply(&instance_1, asset_id_1).await, Some(cap));
+        assert_eq!(
+            total_supply(&instance_1, asset_id_1).await,
+            Some(mint_amount)
+        );
+    }
+}
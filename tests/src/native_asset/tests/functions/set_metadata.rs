@@ -1,5 +1,5 @@
 use crate::native_asset::tests::utils::{
-    interface::{metadata, set_metadata},
+    interface::{freeze_metadata, freeze_metadata_key, metadata, remove_metadata, set_metadata},
     setup::{defaults, get_asset_id, setup, Metadata, SetMetadataEvent},
 };
 use fuels::types::{Bits256, Bytes, Bytes32, Identity};
@@ -428,6 +428,178 @@ mod success {
             }
         );
     }
+
+    #[tokio::test]
+    async fn removes_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let metadata1 = Metadata::String(String::from("Fuel NFT Metadata"));
+        let key = String::from("key1");
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key.clone()).await,
+            Some(metadata1)
+        );
+
+        let response = remove_metadata(&instance_1, asset_id_1, key.clone()).await;
+        assert!(response.value);
+        assert_eq!(metadata(&instance_1, asset_id_1, key.clone()).await, None);
+
+        let log = response
+            .decode_logs_with_type::<SetMetadataEvent>()
+            .unwrap();
+        let event = log.first().unwrap();
+
+        assert_eq!(
+            *event,
+            SetMetadataEvent {
+                asset: asset_id_1,
+                metadata: None,
+                key: key,
+                sender: Identity::Address(owner_wallet.address().into()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_multiple_types() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let metadata_and_keys = vec![
+            (
+                Metadata::String(String::from("Fuel NFT Metadata 1")),
+                String::from("key1"),
+            ),
+            (Metadata::Int(1), String::from("key2")),
+            (
+                Metadata::Bytes(
+                    Bytes::from_hex_str(
+                        "0101010101010101010101010101010101010101010101010101010101010101",
+                    )
+                    .expect("failed to convert to bytes"),
+                ),
+                String::from("key3"),
+            ),
+            (Metadata::B256(Bits256([1u8; 32])), String::from("key4")),
+        ];
+
+        for (metadata1, key) in metadata_and_keys.iter() {
+            set_metadata(
+                &instance_1,
+                asset_id_1,
+                key.clone(),
+                Some(metadata1.clone()),
+            )
+            .await;
+        }
+
+        for (_, key) in metadata_and_keys.iter() {
+            let response = remove_metadata(&instance_1, asset_id_1, key.clone()).await;
+            assert!(response.value);
+            assert_eq!(metadata(&instance_1, asset_id_1, key.clone()).await, None);
+        }
+    }
+
+    #[tokio::test]
+    async fn does_not_remove_other_keys_or_assets() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let metadata1 = Metadata::String(String::from("Fuel NFT Metadata"));
+        let key1 = String::from("key1");
+        let key2 = String::from("key2");
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key1.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key2.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_2,
+            key1.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+
+        remove_metadata(&instance_1, asset_id_1, key1.clone()).await;
+
+        assert_eq!(metadata(&instance_1, asset_id_1, key1.clone()).await, None);
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key2).await,
+            Some(metadata1.clone())
+        );
+        assert_eq!(
+            metadata(&instance_1, asset_id_2, key1).await,
+            Some(metadata1)
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_after_remove() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let metadata1 = Metadata::String(String::from("Fuel NFT Metadata"));
+        let metadata2 = Metadata::Int(1);
+        let key = String::from("key1");
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+        remove_metadata(&instance_1, asset_id_1, key.clone()).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(metadata2.clone()),
+        )
+        .await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(metadata2)
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_unset_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let key = String::from("key1");
+
+        let response = remove_metadata(&instance_1, asset_id_1, key.clone()).await;
+
+        assert!(!response.value);
+        assert_eq!(metadata(&instance_1, asset_id_1, key).await, None);
+        let log = response
+            .decode_logs_with_type::<SetMetadataEvent>()
+            .unwrap();
+        assert!(log.is_empty());
+    }
 }
 
 mod revert {
@@ -470,4 +642,69 @@ mod revert {
         )
         .await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "Frozen")]
+    async fn when_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let metadata1 = Metadata::String(String::from("Fuel NFT Metadata"));
+        let key = String::from("key1");
+
+        freeze_metadata(&instance_1, asset_id_1).await;
+
+        set_metadata(&instance_1, asset_id_1, key, Some(metadata1)).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Frozen")]
+    async fn when_key_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let metadata1 = Metadata::String(String::from("Fuel NFT Metadata"));
+        let key = String::from("key1");
+
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+        freeze_metadata_key(&instance_1, asset_id_1, key.clone()).await;
+
+        set_metadata(&instance_1, asset_id_1, key, Some(metadata1)).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Frozen")]
+    async fn when_removing_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let metadata1 = Metadata::String(String::from("Fuel NFT Metadata"));
+        let key = String::from("key1");
+
+        set_metadata(&instance_1, asset_id_1, key.clone(), Some(metadata1)).await;
+        freeze_metadata(&instance_1, asset_id_1).await;
+
+        remove_metadata(&instance_1, asset_id_1, key).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Frozen")]
+    async fn when_removing_frozen_key() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let metadata1 = Metadata::String(String::from("Fuel NFT Metadata"));
+        let key = String::from("key1");
+
+        set_metadata(&instance_1, asset_id_1, key.clone(), Some(metadata1)).await;
+        freeze_metadata_key(&instance_1, asset_id_1, key.clone()).await;
+
+        remove_metadata(&instance_1, asset_id_1, key).await;
+    }
 }
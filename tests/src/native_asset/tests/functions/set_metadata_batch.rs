@@ -0,0 +1,141 @@
+use crate::native_asset::tests::utils::{
+    interface::{freeze_metadata, metadata, set_metadata_batch},
+    setup::{defaults, setup, Metadata, SetMetadataEvent},
+};
+use fuels::types::{Bits256, Bytes, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_many_entries() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let entries = vec![
+            (
+                asset_id_1,
+                String::from("key1"),
+                Metadata::String(String::from("Fuel NFT Metadata 1")),
+            ),
+            (asset_id_1, String::from("key2"), Metadata::Int(1)),
+            (
+                asset_id_2,
+                String::from("key1"),
+                Metadata::Bytes(Bytes(vec![1u8; 32])),
+            ),
+            (
+                asset_id_2,
+                String::from("key2"),
+                Metadata::B256(Bits256([1u8; 32])),
+            ),
+        ];
+
+        let response = set_metadata_batch(&instance_1, entries.clone()).await;
+
+        for (asset, key, metadata1) in entries.iter() {
+            assert_eq!(
+                metadata(&instance_1, *asset, key.clone()).await,
+                Some(metadata1.clone())
+            );
+        }
+
+        let log = response
+            .decode_logs_with_type::<SetMetadataEvent>()
+            .unwrap();
+        assert_eq!(log.len(), entries.len());
+        for (event, (asset, key, metadata1)) in log.iter().zip(entries.iter()) {
+            assert_eq!(
+                *event,
+                SetMetadataEvent {
+                    asset: *asset,
+                    metadata: Some(metadata1.clone()),
+                    key: key.clone(),
+                    sender: Identity::Address(owner_wallet.address().into()),
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn last_entry_wins_for_same_key() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_batch(
+            &instance_1,
+            vec![
+                (asset_id_1, key.clone(), Metadata::Int(1)),
+                (asset_id_1, key.clone(), Metadata::Int(2)),
+            ],
+        )
+        .await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(Metadata::Int(2))
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_empty_batch() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        let response = set_metadata_batch(&instance_1, vec![]).await;
+
+        let log = response
+            .decode_logs_with_type::<SetMetadataEvent>()
+            .unwrap();
+        assert!(log.is_empty());
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "EmptyString")]
+    async fn when_any_empty_string() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        set_metadata_batch(
+            &instance_1,
+            vec![
+                (asset_id_1, String::from("key1"), Metadata::Int(1)),
+                (
+                    asset_id_1,
+                    String::from("key2"),
+                    Metadata::String(String::from("")),
+                ),
+            ],
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "Frozen")]
+    async fn when_any_asset_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        freeze_metadata(&instance_1, asset_id_2).await;
+
+        set_metadata_batch(
+            &instance_1,
+            vec![
+                (asset_id_1, String::from("key1"), Metadata::Int(1)),
+                (asset_id_2, String::from("key1"), Metadata::Int(1)),
+            ],
+        )
+        .await;
+    }
+}
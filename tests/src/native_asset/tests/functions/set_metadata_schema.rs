@@ -0,0 +1,507 @@
+use crate::native_asset::tests::utils::{
+    interface::{
+        metadata, metadata_schema, remove_metadata_schema, set_default_metadata, set_metadata,
+        set_metadata_schema,
+    },
+    setup::{defaults, setup, Metadata, MetadataSchema, MetadataType},
+};
+use fuels::types::{Bits256, Bytes};
+
+fn schema(metadata_type: MetadataType, max_length: Option<u64>) -> MetadataSchema {
+    MetadataSchema {
+        metadata_type,
+        max_length,
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_metadata_schema() {
+        let (_owner_wallet, _other_wallet, _id, instance_1, _instance_2) = setup().await;
+        let key = String::from("image");
+
+        assert_eq!(metadata_schema(&instance_1, key.clone()).await, None);
+
+        set_metadata_schema(
+            &instance_1,
+            key.clone(),
+            schema(MetadataType::String, Some(256)),
+        )
+        .await;
+
+        assert_eq!(
+            metadata_schema(&instance_1, key).await,
+            Some(schema(MetadataType::String, Some(256)))
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_metadata_schema() {
+        let (_owner_wallet, _other_wallet, _id, instance_1, _instance_2) = setup().await;
+        let key = String::from("image");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::String, None)).await;
+        set_metadata_schema(
+            &instance_1,
+            key.clone(),
+            schema(MetadataType::Bytes, Some(32)),
+        )
+        .await;
+
+        assert_eq!(
+            metadata_schema(&instance_1, key).await,
+            Some(schema(MetadataType::Bytes, Some(32)))
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_metadata_schema() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("image");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::String, None)).await;
+        remove_metadata_schema(&instance_1, key.clone()).await;
+
+        assert_eq!(metadata_schema(&instance_1, key.clone()).await, None);
+
+        set_metadata(&instance_1, asset_id_1, key.clone(), Some(Metadata::Int(1))).await;
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(Metadata::Int(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_affect_existing_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("image");
+
+        set_metadata(&instance_1, asset_id_1, key.clone(), Some(Metadata::Int(1))).await;
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::String, None)).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(Metadata::Int(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_affect_other_keys() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        set_metadata_schema(
+            &instance_1,
+            String::from("image"),
+            schema(MetadataType::String, None),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            String::from("key1"),
+            Some(Metadata::Int(1)),
+        )
+        .await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("key1")).await,
+            Some(Metadata::Int(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_int_when_int_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+        let metadata1 = Metadata::Int(1);
+
+        set_metadata_schema(
+            &instance_1,
+            key.clone(),
+            schema(MetadataType::Int, Some(32)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(metadata1)
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_b256_when_b256_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+        let metadata1 = Metadata::B256(Bits256([1u8; 32]));
+
+        set_metadata_schema(
+            &instance_1,
+            key.clone(),
+            schema(MetadataType::B256, Some(32)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(metadata1)
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_string_when_string_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+        let metadata1 = Metadata::String(String::from("Fuel"));
+
+        set_metadata_schema(
+            &instance_1,
+            key.clone(),
+            schema(MetadataType::String, Some(32)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(metadata1)
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_bytes_when_bytes_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+        let metadata1 = Metadata::Bytes(Bytes(vec![1u8; 32]));
+
+        set_metadata_schema(
+            &instance_1,
+            key.clone(),
+            schema(MetadataType::Bytes, Some(32)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key.clone(),
+            Some(metadata1.clone()),
+        )
+        .await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(metadata1)
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_default_metadata_of_registered_type() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("royalty");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::Int, None)).await;
+        set_default_metadata(&instance_1, key.clone(), Metadata::Int(500)).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, key).await,
+            Some(Metadata::Int(500))
+        );
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_int_and_b256_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::B256, None)).await;
+        set_metadata(&instance_1, asset_id_1, key, Some(Metadata::Int(1))).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_int_and_string_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::String, None)).await;
+        set_metadata(&instance_1, asset_id_1, key, Some(Metadata::Int(1))).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_int_and_bytes_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::Bytes, None)).await;
+        set_metadata(&instance_1, asset_id_1, key, Some(Metadata::Int(1))).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_b256_and_int_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::Int, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::B256(Bits256([1u8; 32]))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_b256_and_string_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::String, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::B256(Bits256([1u8; 32]))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_b256_and_bytes_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::Bytes, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::B256(Bits256([1u8; 32]))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_string_and_int_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::Int, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::String(String::from("Fuel"))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_string_and_b256_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::B256, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::String(String::from("Fuel"))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_string_and_bytes_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::Bytes, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::String(String::from("Fuel"))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_bytes_and_int_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::Int, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::Bytes(Bytes(vec![1u8; 32]))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_bytes_and_b256_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::B256, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::Bytes(Bytes(vec![1u8; 32]))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_bytes_and_string_registered() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::String, None)).await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::Bytes(Bytes(vec![1u8; 32]))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxLengthExceeded")]
+    async fn when_string_exceeds_max_length() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(
+            &instance_1,
+            key.clone(),
+            schema(MetadataType::String, Some(3)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::String(String::from("Fuel"))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MaxLengthExceeded")]
+    async fn when_bytes_exceeds_max_length() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let key = String::from("key1");
+
+        set_metadata_schema(
+            &instance_1,
+            key.clone(),
+            schema(MetadataType::Bytes, Some(3)),
+        )
+        .await;
+        set_metadata(
+            &instance_1,
+            asset_id_1,
+            key,
+            Some(Metadata::Bytes(Bytes(vec![1u8; 32]))),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "TypeMismatch")]
+    async fn when_default_metadata_mismatches() {
+        let (_owner_wallet, _other_wallet, _id, instance_1, _instance_2) = setup().await;
+        let key = String::from("royalty");
+
+        set_metadata_schema(&instance_1, key.clone(), schema(MetadataType::Int, None)).await;
+        set_default_metadata(&instance_1, key, Metadata::String(String::from("500"))).await;
+    }
+}
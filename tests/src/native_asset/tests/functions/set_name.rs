@@ -1,5 +1,5 @@
 use crate::native_asset::tests::utils::{
-    interface::{name, set_name},
+    interface::{freeze_metadata, name, set_name},
     setup::{defaults, get_asset_id, setup, SetNameEvent},
 };
 use fuels::types::{Bytes32, Identity};
@@ -231,4 +231,17 @@ mod revert {
 
         set_name(&instance_1, asset_id_1, Some(name_1)).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "Frozen")]
+    async fn when_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let name_1 = String::from("Fuel Asset 1");
+
+        freeze_metadata(&instance_1, asset_id_1).await;
+
+        set_name(&instance_1, asset_id_1, Some(name_1)).await;
+    }
 }
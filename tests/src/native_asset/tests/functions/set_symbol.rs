@@ -1,5 +1,5 @@
 use crate::native_asset::tests::utils::{
-    interface::{set_symbol, symbol},
+    interface::{freeze_metadata, set_symbol, symbol},
     setup::{defaults, get_asset_id, setup, SetSymbolEvent},
 };
 use fuels::types::{Bytes32, Identity};
@@ -261,4 +261,17 @@ mod revert {
 
         set_symbol(&instance_1, asset_id_1, Some(symbol_1)).await;
     }
+
+    #[tokio::test]
+    #[should_panic(expected = "Frozen")]
+    async fn when_frozen() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let symbol_1 = String::from("FA1");
+
+        freeze_metadata(&instance_1, asset_id_1).await;
+
+        set_symbol(&instance_1, asset_id_1, Some(symbol_1)).await;
+    }
 }
@@ -0,0 +1,100 @@
+use crate::native_asset::tests::utils::{
+    interface::{
+        asset_id_from_sub_id, mint, sub_id_from_collection, sub_id_from_serial, sub_id_from_string,
+        total_supply,
+    },
+    setup::{defaults, get_wallet_balance, setup},
+};
+use asset_ids::asset_id;
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn serial_matches_off_chain() {
+        let (_owner_wallet, _other_wallet, _id, instance_1, _instance_2) = setup().await;
+
+        for serial in [0, 1, 2, 255, 256, u32::MAX as u64, u64::MAX] {
+            assert_eq!(
+                sub_id_from_serial(&instance_1, serial).await,
+                asset_ids::sub_id_from_serial(serial)
+            );
+        }
+        assert_ne!(
+            asset_ids::sub_id_from_serial(1),
+            asset_ids::sub_id_from_serial(2)
+        );
+    }
+
+    #[tokio::test]
+    async fn string_matches_off_chain() {
+        let (_owner_wallet, _other_wallet, _id, instance_1, _instance_2) = setup().await;
+
+        for name in ["a", "Gold", "Fuel Punks #1", "ünïcödé"] {
+            assert_eq!(
+                sub_id_from_string(&instance_1, String::from(name)).await,
+                asset_ids::sub_id_from_string(name)
+            );
+        }
+        assert_ne!(
+            asset_ids::sub_id_from_string("Gold"),
+            asset_ids::sub_id_from_string("Silver")
+        );
+    }
+
+    #[tokio::test]
+    async fn collection_matches_off_chain() {
+        let (_owner_wallet, _other_wallet, _id, instance_1, _instance_2) = setup().await;
+        let collection_1 = asset_ids::sub_id_from_string("Fuel Punks");
+        let collection_2 = Bits256([7u8; 32]);
+
+        for (collection, index) in [
+            (collection_1, 0),
+            (collection_1, 42),
+            (collection_2, 42),
+            (collection_2, u64::MAX),
+        ] {
+            assert_eq!(
+                sub_id_from_collection(&instance_1, collection, index).await,
+                asset_ids::sub_id_from_collection(collection, index)
+            );
+        }
+        assert_ne!(
+            asset_ids::sub_id_from_collection(collection_1, 42),
+            asset_ids::sub_id_from_collection(collection_2, 42)
+        );
+    }
+
+    #[tokio::test]
+    async fn asset_id_matches_off_chain() {
+        let (_owner_wallet, _other_wallet, id, instance_1, _instance_2) = setup().await;
+
+        for sub_id in [
+            Bits256([0u8; 32]),
+            asset_ids::sub_id_from_serial(1),
+            asset_ids::sub_id_from_string("Gold"),
+        ] {
+            assert_eq!(
+                asset_id_from_sub_id(&instance_1, sub_id).await,
+                asset_id(id, sub_id)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn mints_derived_asset() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet.clone());
+        let collection = asset_ids::sub_id_from_string("Fuel Punks");
+        let sub_id = asset_ids::sub_id_from_collection(collection, 7);
+        let asset = asset_id(id, sub_id);
+
+        mint(&instance_1, other_identity, Some(sub_id), 1).await;
+
+        assert_eq!(get_wallet_balance(&other_wallet, &asset).await, 1);
+        assert_eq!(total_supply(&instance_1, asset).await, Some(1));
+    }
+}
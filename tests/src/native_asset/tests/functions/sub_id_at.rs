@@ -0,0 +1,58 @@
+use crate::native_asset::tests::utils::{
+    interface::{mint, mint_batch, sub_id_at, total_assets},
+    setup::{defaults, setup},
+};
+use fuels::types::Bits256;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_none_when_not_minted() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        assert_eq!(sub_id_at(&instance_1, 0).await, None);
+    }
+
+    #[tokio::test]
+    async fn enumerates_many_sub_ids() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let sub_ids: Vec<Bits256> = (1..=10u8).map(|i| Bits256([i; 32])).collect();
+
+        mint_batch(
+            &instance_1,
+            sub_ids
+                .iter()
+                .map(|sub_id| (identity2.clone(), *sub_id, 100))
+                .collect(),
+        )
+        .await;
+
+        assert_eq!(total_assets(&instance_1).await, sub_ids.len() as u64);
+        for (index, sub_id) in sub_ids.iter().enumerate() {
+            assert_eq!(sub_id_at(&instance_1, index as u64).await, Some(*sub_id));
+        }
+        assert_eq!(sub_id_at(&instance_1, sub_ids.len() as u64).await, None);
+    }
+
+    #[tokio::test]
+    async fn enumerates_in_mint_order() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (_asset_id_1, _asset_id_2, sub_id_1, sub_id_2, _identity1, identity2) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, identity2.clone(), Some(sub_id_2), 100).await;
+        mint(&instance_1, identity2.clone(), Some(sub_id_1), 100).await;
+        mint(&instance_1, identity2, Some(sub_id_2), 100).await;
+
+        assert_eq!(total_assets(&instance_1).await, 2);
+        assert_eq!(sub_id_at(&instance_1, 0).await, Some(sub_id_2));
+        assert_eq!(sub_id_at(&instance_1, 1).await, Some(sub_id_1));
+        assert_eq!(sub_id_at(&instance_1, 2).await, None);
+    }
+}
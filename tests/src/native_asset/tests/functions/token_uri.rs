@@ -0,0 +1,39 @@
+use crate::native_asset::tests::utils::{
+    interface::{metadata, set_token_uri, token_uri},
+    setup::{defaults, setup, Metadata},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_token_uri() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let uri = String::from("ipfs://QmExample");
+
+        assert_eq!(token_uri(&instance_1, asset_id_1).await, None);
+
+        set_token_uri(&instance_1, asset_id_1, uri.clone()).await;
+
+        assert_eq!(token_uri(&instance_1, asset_id_1).await, Some(uri.clone()));
+        assert_eq!(token_uri(&instance_1, asset_id_2).await, None);
+    }
+
+    #[tokio::test]
+    async fn stored_as_src7_metadata() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, _sub_id_1, _sub_id_2, _identity1, _identity2) =
+            defaults(id, owner_wallet, other_wallet);
+        let uri = String::from("ipfs://QmExample");
+
+        set_token_uri(&instance_1, asset_id_1, uri.clone()).await;
+
+        assert_eq!(
+            metadata(&instance_1, asset_id_1, String::from("uri")).await,
+            Some(Metadata::String(uri))
+        );
+    }
+}
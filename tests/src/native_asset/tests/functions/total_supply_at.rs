@@ -0,0 +1,158 @@
+use crate::native_asset::tests::utils::{
+    interface::{burn, burn_batch, mint, mint_batch, total_supply_at},
+    setup::{block_height, defaults, produce_blocks, setup},
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn none_before_mint() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet);
+
+        let height = block_height(&owner_wallet).await;
+        assert_eq!(total_supply_at(&instance_1, asset_id_1, height).await, None);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        let mint_height = block_height(&owner_wallet).await;
+
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, mint_height - 1).await,
+            None
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, mint_height).await,
+            Some(100)
+        );
+    }
+
+    #[tokio::test]
+    async fn tracks_mints_and_burns_across_blocks() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        let height_1 = block_height(&owner_wallet).await;
+        produce_blocks(&owner_wallet, 5).await;
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 50).await;
+        let height_2 = block_height(&owner_wallet).await;
+        produce_blocks(&owner_wallet, 5).await;
+
+        burn(&instance_1, asset_id_1, sub_id_1, 30).await;
+        let height_3 = block_height(&owner_wallet).await;
+        produce_blocks(&owner_wallet, 5).await;
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 10).await;
+        let height_4 = block_height(&owner_wallet).await;
+
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_1 - 1).await,
+            None
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_1).await,
+            Some(100)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_2 - 1).await,
+            Some(100)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_2).await,
+            Some(150)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_3 - 1).await,
+            Some(150)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_3).await,
+            Some(120)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_4 - 1).await,
+            Some(120)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_4).await,
+            Some(130)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_4 + 100).await,
+            Some(130)
+        );
+    }
+
+    #[tokio::test]
+    async fn tracks_assets_separately() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        let height_1 = block_height(&owner_wallet).await;
+        produce_blocks(&owner_wallet, 2).await;
+
+        mint(&instance_1, owner_identity, Some(sub_id_2), 200).await;
+        let height_2 = block_height(&owner_wallet).await;
+
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_2).await,
+            Some(100)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_2, height_1).await,
+            None
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_2, height_2).await,
+            Some(200)
+        );
+    }
+
+    #[tokio::test]
+    async fn tracks_batches() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, sub_id_2, _owner_identity, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet);
+        let contract_identity = Identity::ContractId(id);
+
+        mint_batch(
+            &instance_1,
+            vec![
+                (contract_identity.clone(), sub_id_1, 100),
+                (contract_identity.clone(), sub_id_1, 50),
+                (contract_identity, sub_id_2, 200),
+            ],
+        )
+        .await;
+        let height_1 = block_height(&owner_wallet).await;
+        produce_blocks(&owner_wallet, 3).await;
+
+        burn_batch(&instance_1, vec![(sub_id_1, 25), (sub_id_2, 75)]).await;
+        let height_2 = block_height(&owner_wallet).await;
+
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_1).await,
+            Some(150)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_2, height_1).await,
+            Some(200)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_1, height_2).await,
+            Some(125)
+        );
+        assert_eq!(
+            total_supply_at(&instance_1, asset_id_2, height_2).await,
+            Some(125)
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::native_asset::tests::utils::{
+    interface::{allowance, approve, deposit, ledger_balance, mint, transfer_from, withdraw},
+    setup::{defaults, get_wallet_balance, setup, LedgerTransfer},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn transfers_on_behalf_of_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+        let deposit_amount = 100;
+        let approve_amount = 60;
+        let transfer_amount = 40;
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), deposit_amount).await;
+        deposit(&instance_1, owner_identity, asset_id_1, deposit_amount).await;
+        approve(&instance_1, other_identity, asset_id_1, approve_amount).await;
+
+        let response = transfer_from(
+            &instance_2,
+            owner_identity,
+            other_identity,
+            asset_id_1,
+            transfer_amount,
+        )
+        .await;
+
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_1).await,
+            deposit_amount - transfer_amount
+        );
+        assert_eq!(
+            ledger_balance(&instance_1, other_identity, asset_id_1).await,
+            transfer_amount
+        );
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_1).await,
+            approve_amount - transfer_amount
+        );
+        let log = response.decode_logs_with_type::<LedgerTransfer>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            LedgerTransfer {
+                from: owner_identity,
+                to: other_identity,
+                asset: asset_id_1,
+                amount: transfer_amount,
+                sender: other_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn transfers_entire_allowance() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        approve(&instance_1, other_identity, asset_id_1, 50).await;
+        transfer_from(&instance_2, owner_identity, other_identity, asset_id_1, 20).await;
+        transfer_from(&instance_2, owner_identity, other_identity, asset_id_1, 30).await;
+
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_1).await,
+            0
+        );
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_1).await,
+            50
+        );
+        assert_eq!(
+            ledger_balance(&instance_1, other_identity, asset_id_1).await,
+            50
+        );
+    }
+
+    #[tokio::test]
+    async fn transfers_to_owner() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        approve(&instance_1, other_identity, asset_id_1, 50).await;
+        transfer_from(&instance_2, owner_identity, owner_identity, asset_id_1, 50).await;
+
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_1).await,
+            100
+        );
+        assert_eq!(
+            allowance(&instance_1, owner_identity, other_identity, asset_id_1).await,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn recipient_withdraws_transferred_coins() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet.clone());
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        approve(&instance_1, other_identity, asset_id_1, 100).await;
+        transfer_from(&instance_2, owner_identity, other_identity, asset_id_1, 100).await;
+        withdraw(&instance_2, other_identity, asset_id_1, 100).await;
+
+        assert_eq!(
+            ledger_balance(&instance_1, other_identity, asset_id_1).await,
+            0
+        );
+        assert_eq!(get_wallet_balance(&other_wallet, &asset_id_1).await, 100);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroAmount")]
+    async fn when_zero_amount() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        approve(&instance_1, other_identity, asset_id_1, 100).await;
+        transfer_from(&instance_2, owner_identity, other_identity, asset_id_1, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientAllowance")]
+    async fn when_not_approved() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        transfer_from(&instance_2, owner_identity, other_identity, asset_id_1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientAllowance")]
+    async fn when_exceeds_allowance() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        approve(&instance_1, other_identity, asset_id_1, 50).await;
+        transfer_from(&instance_2, owner_identity, other_identity, asset_id_1, 51).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientAllowance")]
+    async fn when_approved_for_another_asset() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        approve(&instance_1, other_identity, asset_id_2, 100).await;
+        transfer_from(&instance_2, owner_identity, other_identity, asset_id_1, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientBalance")]
+    async fn when_insufficient_balance() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 50).await;
+        approve(&instance_1, other_identity, asset_id_1, 100).await;
+        transfer_from(&instance_2, owner_identity, other_identity, asset_id_1, 51).await;
+    }
+}
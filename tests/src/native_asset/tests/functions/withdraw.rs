@@ -0,0 +1,107 @@
+use crate::native_asset::tests::utils::{
+    interface::{deposit, ledger_balance, mint, withdraw},
+    setup::{defaults, get_contract_balance, get_wallet_balance, setup, LedgerWithdrawal},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn withdraws_coins() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet.clone());
+        let deposit_amount = 100;
+        let withdraw_amount = 40;
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), deposit_amount).await;
+        deposit(&instance_1, owner_identity, asset_id_1, deposit_amount).await;
+
+        let response = withdraw(&instance_1, other_identity, asset_id_1, withdraw_amount).await;
+
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_1).await,
+            deposit_amount - withdraw_amount
+        );
+        assert_eq!(
+            get_wallet_balance(&other_wallet, &asset_id_1).await,
+            withdraw_amount
+        );
+        assert_eq!(
+            get_contract_balance(&owner_wallet, id, &asset_id_1).await,
+            deposit_amount - withdraw_amount
+        );
+        let log = response
+            .decode_logs_with_type::<LedgerWithdrawal>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            LedgerWithdrawal {
+                owner: owner_identity,
+                recipient: other_identity,
+                asset: asset_id_1,
+                amount: withdraw_amount,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn withdraws_entire_balance() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet.clone(), other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        withdraw(&instance_1, owner_identity, asset_id_1, 100).await;
+
+        assert_eq!(
+            ledger_balance(&instance_1, owner_identity, asset_id_1).await,
+            0
+        );
+        assert_eq!(get_wallet_balance(&owner_wallet, &asset_id_1).await, 100);
+    }
+}
+
+mod revert {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "ZeroAmount")]
+    async fn when_zero_amount() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        withdraw(&instance_1, owner_identity, asset_id_1, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientBalance")]
+    async fn when_insufficient_balance() {
+        let (owner_wallet, other_wallet, id, instance_1, _instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, _other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 50).await;
+        withdraw(&instance_1, owner_identity, asset_id_1, 51).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InsufficientBalance")]
+    async fn when_withdrawing_another_users_balance() {
+        let (owner_wallet, other_wallet, id, instance_1, instance_2) = setup().await;
+        let (asset_id_1, _asset_id_2, sub_id_1, _sub_id_2, owner_identity, other_identity) =
+            defaults(id, owner_wallet, other_wallet);
+
+        mint(&instance_1, owner_identity, Some(sub_id_1), 100).await;
+        deposit(&instance_1, owner_identity, asset_id_1, 100).await;
+        withdraw(&instance_2, other_identity, asset_id_1, 1).await;
+    }
+}
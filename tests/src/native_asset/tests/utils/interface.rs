@@ -1,4 +1,4 @@
-use crate::native_asset::tests::utils::setup::{AssetLib, Metadata};
+use crate::native_asset::tests::utils::setup::{AccessPolicy, AssetLib, Metadata, MetadataSchema};
 use fuels::{
     prelude::{AssetId, CallParameters, TxPolicies, WalletUnlocked},
     programs::responses::CallResponse,
@@ -146,3 +146,588 @@ pub(crate) async fn set_metadata(
         .await
         .unwrap()
 }
+
+pub(crate) async fn remove_metadata(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+    key: String,
+) -> CallResponse<bool> {
+    contract
+        .methods()
+        .remove_metadata(asset, key)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn set_metadata_schema(
+    contract: &AssetLib<WalletUnlocked>,
+    key: String,
+    schema: MetadataSchema,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .set_metadata_schema(key, schema)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn remove_metadata_schema(
+    contract: &AssetLib<WalletUnlocked>,
+    key: String,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .remove_metadata_schema(key)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn metadata_schema(
+    contract: &AssetLib<WalletUnlocked>,
+    key: String,
+) -> Option<MetadataSchema> {
+    contract
+        .methods()
+        .metadata_schema(key)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn set_default_metadata(
+    contract: &AssetLib<WalletUnlocked>,
+    key: String,
+    metadata: Metadata,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .set_default_metadata(key, metadata)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn default_metadata(
+    contract: &AssetLib<WalletUnlocked>,
+    key: String,
+) -> Option<Metadata> {
+    contract
+        .methods()
+        .default_metadata(key)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn is_default_metadata(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+    key: String,
+) -> bool {
+    contract
+        .methods()
+        .is_default_metadata(asset, key)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn set_metadata_batch(
+    contract: &AssetLib<WalletUnlocked>,
+    entries: Vec<(AssetId, String, Metadata)>,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .set_metadata_batch(entries)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn metadata_batch(
+    contract: &AssetLib<WalletUnlocked>,
+    queries: Vec<(AssetId, String)>,
+) -> Vec<Option<Metadata>> {
+    contract
+        .methods()
+        .metadata_batch(queries)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn metadata_keys_len(contract: &AssetLib<WalletUnlocked>, asset: AssetId) -> u64 {
+    contract
+        .methods()
+        .metadata_keys_len(asset)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn metadata_key_at(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+    index: u64,
+) -> Option<String> {
+    contract
+        .methods()
+        .metadata_key_at(asset, index)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn freeze_metadata(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .freeze_metadata(asset)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn is_metadata_frozen(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+) -> bool {
+    contract
+        .methods()
+        .is_metadata_frozen(asset)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn freeze_metadata_key(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+    key: String,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .freeze_metadata_key(asset, key)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn is_metadata_key_frozen(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+    key: String,
+) -> bool {
+    contract
+        .methods()
+        .is_metadata_key_frozen(asset, key)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn burn_forwarded(
+    contract: &AssetLib<WalletUnlocked>,
+    asset_id: AssetId,
+    sub_id: Bits256,
+    amount: u64,
+) -> CallResponse<u64> {
+    let call_params = CallParameters::new(amount, asset_id, 1_000_000);
+
+    contract
+        .methods()
+        .burn_forwarded(sub_id)
+        .with_tx_policies(TxPolicies::default().with_script_gas_limit(2_000_000))
+        .call_params(call_params)
+        .unwrap()
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn deposit(
+    contract: &AssetLib<WalletUnlocked>,
+    recipient: Identity,
+    asset_id: AssetId,
+    amount: u64,
+) -> CallResponse<u64> {
+    let call_params = CallParameters::new(amount, asset_id, 1_000_000);
+
+    contract
+        .methods()
+        .deposit(recipient)
+        .with_tx_policies(TxPolicies::default().with_script_gas_limit(2_000_000))
+        .call_params(call_params)
+        .unwrap()
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn withdraw(
+    contract: &AssetLib<WalletUnlocked>,
+    recipient: Identity,
+    asset_id: AssetId,
+    amount: u64,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .withdraw(recipient, asset_id, amount)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn ledger_balance(
+    contract: &AssetLib<WalletUnlocked>,
+    owner: Identity,
+    asset_id: AssetId,
+) -> u64 {
+    contract
+        .methods()
+        .ledger_balance(owner, asset_id)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn approve(
+    contract: &AssetLib<WalletUnlocked>,
+    spender: Identity,
+    asset_id: AssetId,
+    amount: u64,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .approve(spender, asset_id, amount)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn allowance(
+    contract: &AssetLib<WalletUnlocked>,
+    owner: Identity,
+    spender: Identity,
+    asset_id: AssetId,
+) -> u64 {
+    contract
+        .methods()
+        .allowance(owner, spender, asset_id)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn transfer_from(
+    contract: &AssetLib<WalletUnlocked>,
+    owner: Identity,
+    recipient: Identity,
+    asset_id: AssetId,
+    amount: u64,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .transfer_from(owner, recipient, asset_id, amount)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn sub_id_from_serial(
+    contract: &AssetLib<WalletUnlocked>,
+    serial: u64,
+) -> Bits256 {
+    contract
+        .methods()
+        .sub_id_from_serial(serial)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn sub_id_from_string(
+    contract: &AssetLib<WalletUnlocked>,
+    name: String,
+) -> Bits256 {
+    contract
+        .methods()
+        .sub_id_from_string(name)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn sub_id_from_collection(
+    contract: &AssetLib<WalletUnlocked>,
+    collection: Bits256,
+    index: u64,
+) -> Bits256 {
+    contract
+        .methods()
+        .sub_id_from_collection(collection, index)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn asset_id_from_sub_id(
+    contract: &AssetLib<WalletUnlocked>,
+    sub_id: Bits256,
+) -> AssetId {
+    contract
+        .methods()
+        .asset_id_from_sub_id(sub_id)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn guarded_mint(
+    contract: &AssetLib<WalletUnlocked>,
+    policy: AccessPolicy,
+    recipient: Identity,
+    sub_id: Bits256,
+    amount: u64,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .guarded_mint(policy, recipient, sub_id, amount)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn guarded_burn(
+    contract: &AssetLib<WalletUnlocked>,
+    policy: AccessPolicy,
+    asset_id: AssetId,
+    sub_id: Bits256,
+    amount: u64,
+) -> CallResponse<()> {
+    let call_params = CallParameters::new(amount, asset_id, 1_000_000);
+
+    contract
+        .methods()
+        .guarded_burn(policy, sub_id, amount)
+        .with_tx_policies(TxPolicies::default().with_script_gas_limit(2_000_000))
+        .call_params(call_params)
+        .unwrap()
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn initialize_ownership(
+    contract: &AssetLib<WalletUnlocked>,
+    owner: Identity,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .initialize_ownership(owner)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn add_admin(
+    contract: &AssetLib<WalletUnlocked>,
+    admin: Identity,
+) -> CallResponse<()> {
+    contract.methods().add_admin(admin).call().await.unwrap()
+}
+
+pub(crate) async fn grant_role(
+    contract: &AssetLib<WalletUnlocked>,
+    role: Bits256,
+    account: Identity,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .grant_role(role, account)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn pause(contract: &AssetLib<WalletUnlocked>) -> CallResponse<()> {
+    contract.methods().pause().call().await.unwrap()
+}
+
+pub(crate) async fn unpause(contract: &AssetLib<WalletUnlocked>) -> CallResponse<()> {
+    contract.methods().unpause().call().await.unwrap()
+}
+
+pub(crate) async fn total_supply_at(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+    height: u32,
+) -> Option<u64> {
+    contract
+        .methods()
+        .total_supply_at(asset, height)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn max_supply(contract: &AssetLib<WalletUnlocked>, asset: AssetId) -> Option<u64> {
+    contract
+        .methods()
+        .max_supply(asset)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn set_max_supply(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+    max_supply: u64,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .set_max_supply(asset, max_supply)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn mint_batch(
+    contract: &AssetLib<WalletUnlocked>,
+    recipients: Vec<(Identity, Bits256, u64)>,
+) -> CallResponse<()> {
+    let variable_outputs = recipients.len();
+
+    contract
+        .methods()
+        .mint_batch(recipients)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(variable_outputs))
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn burn_batch(
+    contract: &AssetLib<WalletUnlocked>,
+    burns: Vec<(Bits256, u64)>,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .burn_batch(burns)
+        .with_tx_policies(TxPolicies::default().with_script_gas_limit(2_000_000))
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn mint_nft(
+    contract: &AssetLib<WalletUnlocked>,
+    recipient: Identity,
+    sub_id: Bits256,
+) -> CallResponse<AssetId> {
+    contract
+        .methods()
+        .mint_nft(recipient, sub_id)
+        .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn burn_nft(
+    contract: &AssetLib<WalletUnlocked>,
+    asset_id: AssetId,
+    sub_id: Bits256,
+) -> CallResponse<()> {
+    let call_params = CallParameters::new(1, asset_id, 1_000_000);
+
+    contract
+        .methods()
+        .burn_nft(sub_id)
+        .with_tx_policies(TxPolicies::default().with_script_gas_limit(2_000_000))
+        .call_params(call_params)
+        .unwrap()
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn is_nft_minted(contract: &AssetLib<WalletUnlocked>, asset: AssetId) -> bool {
+    contract
+        .methods()
+        .is_nft_minted(asset)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn set_token_uri(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+    uri: String,
+) -> CallResponse<()> {
+    contract
+        .methods()
+        .set_token_uri(asset, uri)
+        .call()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn token_uri(
+    contract: &AssetLib<WalletUnlocked>,
+    asset: AssetId,
+) -> Option<String> {
+    contract
+        .methods()
+        .token_uri(asset)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn asset_at(contract: &AssetLib<WalletUnlocked>, index: u64) -> Option<AssetId> {
+    contract
+        .methods()
+        .asset_at(index)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
+
+pub(crate) async fn sub_id_at(contract: &AssetLib<WalletUnlocked>, index: u64) -> Option<Bits256> {
+    contract
+        .methods()
+        .sub_id_at(index)
+        .call()
+        .await
+        .unwrap()
+        .value
+}
@@ -1,3 +1,4 @@
+use asset_ids::asset_id;
 use fuels::{
     accounts::ViewOnlyAccount,
     prelude::{
@@ -6,7 +7,6 @@ use fuels::{
     },
     types::{Address, AssetId, Bits256, Bytes32, Identity},
 };
-use sha2::{Digest, Sha256};
 
 abigen!(Contract(
     name = "AssetLib",
@@ -16,6 +16,8 @@ abigen!(Contract(
 const NATIVE_ASSET_TEST_CONTRACT_BINARY_PATH: &str =
     "./src/native_asset/out/release/native_asset_lib.bin";
 
+pub(crate) const MINTER_ROLE: Bits256 = Bits256([1u8; 32]);
+
 pub(crate) fn defaults(
     contract_id: ContractId,
     wallet_1: WalletUnlocked,
@@ -81,12 +83,46 @@ pub(crate) async fn setup() -> (
 }
 
 pub(crate) fn get_asset_id(sub_id: Bytes32, contract: ContractId) -> AssetId {
-    let mut hasher = Sha256::new();
-    hasher.update(*contract);
-    hasher.update(*sub_id);
-    AssetId::new(*Bytes32::from(<[u8; 32]>::from(hasher.finalize())))
+    asset_id(contract, Bits256(*sub_id))
 }
 
 pub(crate) async fn get_wallet_balance(wallet: &WalletUnlocked, asset: &AssetId) -> u64 {
     wallet.get_asset_balance(asset).await.unwrap()
 }
+
+pub(crate) fn recipient_wallets(wallet: &WalletUnlocked, count: usize) -> Vec<WalletUnlocked> {
+    (0..count)
+        .map(|_| WalletUnlocked::new_random(wallet.provider().cloned()))
+        .collect()
+}
+
+pub(crate) async fn get_contract_balance(
+    wallet: &WalletUnlocked,
+    contract: ContractId,
+    asset: &AssetId,
+) -> u64 {
+    wallet
+        .provider()
+        .unwrap()
+        .get_contract_asset_balance(&contract.into(), *asset)
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn block_height(wallet: &WalletUnlocked) -> u32 {
+    wallet
+        .provider()
+        .unwrap()
+        .latest_block_height()
+        .await
+        .unwrap()
+}
+
+pub(crate) async fn produce_blocks(wallet: &WalletUnlocked, count: u32) {
+    wallet
+        .provider()
+        .unwrap()
+        .produce_blocks(count, None)
+        .await
+        .unwrap();
+}
@@ -1,6 +1,24 @@
 contract;
 
-use ownership::{_owner, initialize_ownership, only_owner, renounce_ownership, transfer_ownership};
+use ownership::{
+    _owner,
+    _pending_owner,
+    accept_ownership,
+    cancel_ownership_transfer,
+    initialize_ownership,
+    only_owner,
+    propose_ownership_transfer,
+    renounce_ownership,
+    transfer_ownership,
+};
+use ownership::timelock::{
+    _ownership_delay,
+    _scheduled_ownership_transfer,
+    cancel_scheduled_ownership_transfer,
+    execute_ownership_transfer,
+    initialize_ownership_delay,
+    schedule_ownership_transfer,
+};
 use src5::{SRC5, State};
 
 abi OwnableTest {
@@ -12,6 +30,26 @@ abi OwnableTest {
     fn set_ownership(new_owner: Identity);
     #[storage(read, write)]
     fn transfer_ownership(new_owner: Identity);
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity>;
+    #[storage(read, write)]
+    fn propose_ownership_transfer(new_owner: Identity);
+    #[storage(read, write)]
+    fn accept_ownership();
+    #[storage(read, write)]
+    fn cancel_ownership_transfer();
+    #[storage(read)]
+    fn ownership_delay() -> Option<u64>;
+    #[storage(read, write)]
+    fn initialize_ownership_delay(min_delay: u64);
+    #[storage(read)]
+    fn scheduled_ownership_transfer() -> Option<(Identity, u64)>;
+    #[storage(read, write)]
+    fn schedule_ownership_transfer(new_owner: Identity, delay: u64);
+    #[storage(read, write)]
+    fn execute_ownership_transfer();
+    #[storage(read, write)]
+    fn cancel_scheduled_ownership_transfer();
 }
 
 impl SRC5 for Contract {
@@ -41,4 +79,54 @@ impl OwnableTest for Contract {
     fn transfer_ownership(new_owner: Identity) {
         transfer_ownership(new_owner);
     }
+
+    #[storage(read)]
+    fn pending_owner() -> Option<Identity> {
+        _pending_owner()
+    }
+
+    #[storage(read, write)]
+    fn propose_ownership_transfer(new_owner: Identity) {
+        propose_ownership_transfer(new_owner);
+    }
+
+    #[storage(read, write)]
+    fn accept_ownership() {
+        accept_ownership();
+    }
+
+    #[storage(read, write)]
+    fn cancel_ownership_transfer() {
+        cancel_ownership_transfer();
+    }
+
+    #[storage(read)]
+    fn ownership_delay() -> Option<u64> {
+        _ownership_delay()
+    }
+
+    #[storage(read, write)]
+    fn initialize_ownership_delay(min_delay: u64) {
+        initialize_ownership_delay(min_delay);
+    }
+
+    #[storage(read)]
+    fn scheduled_ownership_transfer() -> Option<(Identity, u64)> {
+        _scheduled_ownership_transfer()
+    }
+
+    #[storage(read, write)]
+    fn schedule_ownership_transfer(new_owner: Identity, delay: u64) {
+        schedule_ownership_transfer(new_owner, delay);
+    }
+
+    #[storage(read, write)]
+    fn execute_ownership_transfer() {
+        execute_ownership_transfer();
+    }
+
+    #[storage(read, write)]
+    fn cancel_scheduled_ownership_transfer() {
+        cancel_scheduled_ownership_transfer();
+    }
 }
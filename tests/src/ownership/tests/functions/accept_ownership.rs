@@ -0,0 +1,156 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{
+        accept_ownership, initialize_ownership_delay, owner, pending_owner,
+        propose_ownership_transfer, schedule_ownership_transfer, scheduled_ownership_transfer,
+        set_ownership, transfer_ownership,
+    },
+    test_helpers::setup,
+    OwnershipTransferred, State,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn accepts_ownership() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        let response = accept_ownership(&owner2.contract).await;
+
+        let owner_enum = match owner(&owner1.contract).await {
+            State::Initialized(owner) => Some(owner),
+            _ => None,
+        };
+        assert!(owner_enum.is_some());
+        assert_eq!(owner_enum.unwrap(), owner2_identity);
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+
+        let log = response
+            .decode_logs_with_type::<OwnershipTransferred>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            OwnershipTransferred {
+                new_owner: owner2_identity,
+                previous_owner: owner1_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_ownership_twice() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+        accept_ownership(&owner2.contract).await;
+
+        propose_ownership_transfer(&owner2.contract, owner1_identity.clone()).await;
+        accept_ownership(&owner1.contract).await;
+
+        let owner_enum = match owner(&owner1.contract).await {
+            State::Initialized(owner) => Some(owner),
+            _ => None,
+        };
+        assert!(owner_enum.is_some());
+        assert_eq!(owner_enum.unwrap(), owner1_identity);
+    }
+
+    #[tokio::test]
+    async fn cancels_scheduled_transfer() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, deployer_identity, 100).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        accept_ownership(&owner2.contract).await;
+
+        assert_eq!(scheduled_ownership_transfer(&owner1.contract).await, None);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_no_pending_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        accept_ownership(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_not_pending_owner() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        accept_ownership(&deployer.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_owner_accepts() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        accept_ownership(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_accepted_twice() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        accept_ownership(&owner2.contract).await;
+        accept_ownership(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_ownership_transferred_directly() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+        transfer_ownership(&owner1.contract, deployer_identity).await;
+
+        accept_ownership(&owner2.contract).await;
+    }
+}
@@ -0,0 +1,82 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{
+        accept_ownership, cancel_ownership_transfer, pending_owner, propose_ownership_transfer,
+        set_ownership,
+    },
+    test_helpers::setup,
+    OwnershipTransferCancelled,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancels_ownership_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        let response = cancel_ownership_transfer(&owner1.contract).await;
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+
+        let log = response
+            .decode_logs_with_type::<OwnershipTransferCancelled>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            OwnershipTransferCancelled {
+                pending_owner: owner2_identity,
+                owner: owner1_identity,
+            }
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        cancel_ownership_transfer(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NoPendingOwner")]
+    async fn when_no_pending_owner() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        cancel_ownership_transfer(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingOwner")]
+    async fn when_accepting_cancelled_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+        cancel_ownership_transfer(&owner1.contract).await;
+
+        accept_ownership(&owner2.contract).await;
+    }
+}
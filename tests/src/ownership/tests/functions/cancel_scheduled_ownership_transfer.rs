@@ -0,0 +1,87 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{
+        cancel_scheduled_ownership_transfer, execute_ownership_transfer,
+        initialize_ownership_delay, schedule_ownership_transfer, scheduled_ownership_transfer,
+        set_ownership,
+    },
+    test_helpers::{advance_time, setup},
+    ScheduledOwnershipTransferCancelled,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancels_scheduled_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+
+        let response = cancel_scheduled_ownership_transfer(&owner1.contract).await;
+        assert_eq!(scheduled_ownership_transfer(&owner1.contract).await, None);
+
+        let log = response
+            .decode_logs_with_type::<ScheduledOwnershipTransferCancelled>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            ScheduledOwnershipTransferCancelled {
+                new_owner: owner2_identity,
+                owner: owner1_identity,
+            }
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+
+        cancel_scheduled_ownership_transfer(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_not_scheduled() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        cancel_scheduled_ownership_transfer(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_executing_cancelled_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+        cancel_scheduled_ownership_transfer(&owner1.contract).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        execute_ownership_transfer(&owner1.contract).await;
+    }
+}
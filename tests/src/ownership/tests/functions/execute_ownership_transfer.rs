@@ -0,0 +1,121 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{
+        execute_ownership_transfer, initialize_ownership_delay, owner, schedule_ownership_transfer,
+        scheduled_ownership_transfer, set_ownership,
+    },
+    test_helpers::{advance_time, setup},
+    OwnershipTransferred, ScheduledOwnershipTransferExecuted, State,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn executes_after_delay() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        let response = execute_ownership_transfer(&owner1.contract).await;
+
+        let owner_enum = match owner(&owner1.contract).await {
+            State::Initialized(owner) => Some(owner),
+            _ => None,
+        };
+        assert_eq!(owner_enum.unwrap(), owner2_identity);
+        assert_eq!(scheduled_ownership_transfer(&owner1.contract).await, None);
+
+        let executed_log = response
+            .decode_logs_with_type::<ScheduledOwnershipTransferExecuted>()
+            .unwrap();
+        let executed_event = executed_log.first().unwrap();
+        assert_eq!(
+            *executed_event,
+            ScheduledOwnershipTransferExecuted {
+                new_owner: owner2_identity.clone(),
+                previous_owner: owner1_identity.clone(),
+            }
+        );
+
+        let transferred_log = response
+            .decode_logs_with_type::<OwnershipTransferred>()
+            .unwrap();
+        let transferred_event = transferred_log.first().unwrap();
+        assert_eq!(
+            *transferred_event,
+            OwnershipTransferred {
+                new_owner: owner2_identity,
+                previous_owner: owner1_identity,
+            }
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotElapsed")]
+    async fn when_delay_not_elapsed() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 1000).await;
+
+        advance_time(&owner1.wallet, 10).await;
+        execute_ownership_transfer(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_not_scheduled() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        execute_ownership_transfer(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_executed_twice() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        execute_ownership_transfer(&owner1.contract).await;
+        execute_ownership_transfer(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        execute_ownership_transfer(&owner2.contract).await;
+    }
+}
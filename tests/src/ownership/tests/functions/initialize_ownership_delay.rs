@@ -0,0 +1,76 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{initialize_ownership_delay, ownership_delay, set_ownership},
+    test_helpers::setup,
+    OwnershipDelaySet,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn initializes_ownership_delay() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        assert_eq!(ownership_delay(&owner1.contract).await, None);
+
+        let response = initialize_ownership_delay(&owner1.contract, 100).await;
+
+        assert_eq!(ownership_delay(&owner1.contract).await, Some(100));
+
+        let log = response
+            .decode_logs_with_type::<OwnershipDelaySet>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            OwnershipDelaySet {
+                min_delay: 100,
+                owner: owner1_identity,
+            }
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        initialize_ownership_delay(&owner2.contract, 100).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayAlreadyInitialized")]
+    async fn when_already_initialized() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+
+        initialize_ownership_delay(&owner1.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayTooShort")]
+    async fn when_zero() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        initialize_ownership_delay(&owner1.contract, 0).await;
+    }
+}
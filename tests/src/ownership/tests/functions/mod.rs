@@ -1,5 +1,14 @@
+mod accept_ownership;
+mod cancel_ownership_transfer;
+mod cancel_scheduled_ownership_transfer;
+mod execute_ownership_transfer;
+mod initialize_ownership_delay;
 mod only_owner;
 mod owner;
+mod pending_owner;
+mod propose_ownership_transfer;
 mod renounce_ownership;
+mod schedule_ownership_transfer;
+mod scheduled_ownership_transfer;
 mod set_ownership;
 mod transfer_ownership;
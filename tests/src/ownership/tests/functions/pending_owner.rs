@@ -0,0 +1,56 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{
+        pending_owner, propose_ownership_transfer, renounce_ownership, set_ownership,
+        transfer_ownership,
+    },
+    test_helpers::setup,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_pending_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+        assert_eq!(pending_owner(&owner1.contract).await, Some(owner2_identity));
+    }
+
+    #[tokio::test]
+    async fn cleared_by_renounce_ownership() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        renounce_ownership(&owner1.contract).await;
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+    }
+
+    #[tokio::test]
+    async fn cleared_by_transfer_ownership() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        transfer_ownership(&owner1.contract, deployer_identity).await;
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{owner, pending_owner, propose_ownership_transfer, set_ownership},
+    test_helpers::setup,
+    OwnershipTransferProposed, State,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn proposes_ownership_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        assert_eq!(pending_owner(&owner1.contract).await, None);
+
+        let response = propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+
+        assert_eq!(
+            pending_owner(&owner1.contract).await,
+            Some(owner2_identity.clone())
+        );
+        assert!(matches!(
+            owner(&owner1.contract).await,
+            State::Initialized(owner) if owner == owner1_identity
+        ));
+
+        let log = response
+            .decode_logs_with_type::<OwnershipTransferProposed>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            OwnershipTransferProposed {
+                pending_owner: owner2_identity,
+                owner: owner1_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_previous_proposal() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        propose_ownership_transfer(&owner1.contract, owner2_identity.clone()).await;
+        propose_ownership_transfer(&owner1.contract, deployer_identity.clone()).await;
+
+        assert_eq!(
+            pending_owner(&owner1.contract).await,
+            Some(deployer_identity)
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        propose_ownership_transfer(&owner2.contract, owner2_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_ownership_not_initialized() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+
+        propose_ownership_transfer(&owner1.contract, owner2_identity).await;
+    }
+}
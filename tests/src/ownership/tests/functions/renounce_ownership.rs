@@ -1,5 +1,8 @@
 use crate::ownership::tests::utils::{
-    abi_calls::{owner, renounce_ownership, set_ownership},
+    abi_calls::{
+        initialize_ownership_delay, owner, renounce_ownership, schedule_ownership_transfer,
+        scheduled_ownership_transfer, set_ownership,
+    },
     test_helpers::setup,
     State,
 };
@@ -27,6 +30,21 @@ mod success {
 
         assert!(matches!(owner(&owner1.contract).await, State::Revoked));
     }
+
+    #[tokio::test]
+    async fn cancels_scheduled_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity, 100).await;
+
+        renounce_ownership(&owner1.contract).await;
+
+        assert_eq!(scheduled_ownership_transfer(&owner1.contract).await, None);
+    }
 }
 
 mod reverts {
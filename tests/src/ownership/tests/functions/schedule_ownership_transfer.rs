@@ -0,0 +1,144 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{
+        initialize_ownership_delay, owner, schedule_ownership_transfer,
+        scheduled_ownership_transfer, set_ownership,
+    },
+    test_helpers::setup,
+    OwnershipTransferScheduled, State,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn schedules_ownership_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+
+        let response =
+            schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+
+        let (scheduled_owner, eta) = scheduled_ownership_transfer(&owner1.contract)
+            .await
+            .unwrap();
+        assert_eq!(scheduled_owner, owner2_identity);
+
+        let owner_enum = match owner(&owner1.contract).await {
+            State::Initialized(owner) => Some(owner),
+            _ => None,
+        };
+        assert_eq!(owner_enum.unwrap(), owner1_identity);
+
+        let log = response
+            .decode_logs_with_type::<OwnershipTransferScheduled>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            OwnershipTransferScheduled {
+                new_owner: owner2_identity,
+                eta,
+                owner: owner1_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_scheduled_transfer() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+        let (_, eta1) = scheduled_ownership_transfer(&owner1.contract)
+            .await
+            .unwrap();
+
+        schedule_ownership_transfer(&owner1.contract, deployer_identity.clone(), 200).await;
+        let (scheduled_owner, eta2) = scheduled_ownership_transfer(&owner1.contract)
+            .await
+            .unwrap();
+
+        assert_eq!(scheduled_owner, deployer_identity);
+        assert!(eta2 > eta1);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_not_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+
+        schedule_ownership_transfer(&owner2.contract, owner2_identity, 100).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayNotInitialized")]
+    async fn when_delay_not_initialized() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+
+        schedule_ownership_transfer(&owner1.contract, owner2_identity, 100).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayTooShort")]
+    async fn when_delay_less_than_minimum() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+
+        schedule_ownership_transfer(&owner1.contract, owner2_identity, 99).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayTooShort")]
+    async fn when_delay_zero() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+
+        schedule_ownership_transfer(&owner1.contract, owner2_identity, 0).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayOverflow")]
+    async fn when_delay_overflows() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+
+        schedule_ownership_transfer(&owner1.contract, owner2_identity, u64::MAX).await;
+    }
+}
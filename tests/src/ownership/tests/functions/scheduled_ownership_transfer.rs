@@ -0,0 +1,42 @@
+use crate::ownership::tests::utils::{
+    abi_calls::{
+        cancel_scheduled_ownership_transfer, initialize_ownership_delay,
+        schedule_ownership_transfer, scheduled_ownership_transfer, set_ownership,
+    },
+    test_helpers::setup,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_not_scheduled() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        assert_eq!(scheduled_ownership_transfer(&owner1.contract).await, None);
+
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        assert_eq!(scheduled_ownership_transfer(&owner1.contract).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_scheduled_transfer() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, owner2_identity.clone(), 100).await;
+
+        let scheduled = scheduled_ownership_transfer(&owner1.contract).await;
+        assert!(scheduled.is_some());
+        assert_eq!(scheduled.unwrap().0, owner2_identity);
+
+        cancel_scheduled_ownership_transfer(&owner1.contract).await;
+        assert_eq!(scheduled_ownership_transfer(&owner1.contract).await, None);
+    }
+}
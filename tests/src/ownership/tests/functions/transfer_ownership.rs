@@ -1,5 +1,8 @@
 use crate::ownership::tests::utils::{
-    abi_calls::{owner, set_ownership, transfer_ownership},
+    abi_calls::{
+        initialize_ownership_delay, owner, schedule_ownership_transfer,
+        scheduled_ownership_transfer, set_ownership, transfer_ownership,
+    },
     test_helpers::setup,
     State,
 };
@@ -59,6 +62,22 @@ mod success {
         assert!(owner_enum1.is_some());
         assert_eq!(owner_enum1.unwrap(), owner1_identity);
     }
+
+    #[tokio::test]
+    async fn cancels_scheduled_transfer() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        set_ownership(&owner1.contract, owner1_identity.clone()).await;
+        initialize_ownership_delay(&owner1.contract, 100).await;
+        schedule_ownership_transfer(&owner1.contract, deployer_identity, 100).await;
+
+        transfer_ownership(&owner1.contract, owner2_identity.clone()).await;
+
+        assert_eq!(scheduled_ownership_transfer(&owner1.contract).await, None);
+    }
 }
 
 mod reverts {
@@ -1,7 +1,8 @@
+use chrono::Duration;
 use fuels::{
     prelude::{
         abigen, launch_custom_provider_and_get_wallets, Contract, LoadConfiguration,
-        StorageConfiguration, TxPolicies, WalletUnlocked, WalletsConfig,
+        StorageConfiguration, TxPolicies, ViewOnlyAccount, WalletUnlocked, WalletsConfig,
     },
     programs::responses::CallResponse,
     types::Identity,
@@ -62,6 +63,112 @@ pub mod abi_calls {
             .await
             .unwrap()
     }
+
+    pub async fn pending_owner(contract: &OwnershipLib<WalletUnlocked>) -> Option<Identity> {
+        contract
+            .methods()
+            .pending_owner()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn propose_ownership_transfer(
+        contract: &OwnershipLib<WalletUnlocked>,
+        new_owner: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .propose_ownership_transfer(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn accept_ownership(contract: &OwnershipLib<WalletUnlocked>) -> CallResponse<()> {
+        contract.methods().accept_ownership().call().await.unwrap()
+    }
+
+    pub async fn cancel_ownership_transfer(
+        contract: &OwnershipLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .cancel_ownership_transfer()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn ownership_delay(contract: &OwnershipLib<WalletUnlocked>) -> Option<u64> {
+        contract
+            .methods()
+            .ownership_delay()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn initialize_ownership_delay(
+        contract: &OwnershipLib<WalletUnlocked>,
+        min_delay: u64,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .initialize_ownership_delay(min_delay)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn scheduled_ownership_transfer(
+        contract: &OwnershipLib<WalletUnlocked>,
+    ) -> Option<(Identity, u64)> {
+        contract
+            .methods()
+            .scheduled_ownership_transfer()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn schedule_ownership_transfer(
+        contract: &OwnershipLib<WalletUnlocked>,
+        new_owner: Identity,
+        delay: u64,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .schedule_ownership_transfer(new_owner, delay)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn execute_ownership_transfer(
+        contract: &OwnershipLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .execute_ownership_transfer()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn cancel_scheduled_ownership_transfer(
+        contract: &OwnershipLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .cancel_scheduled_ownership_transfer()
+            .call()
+            .await
+            .unwrap()
+    }
 }
 
 pub mod test_helpers {
@@ -116,4 +223,13 @@ pub mod test_helpers {
 
         (deploy_wallet, owner1, owner2)
     }
+
+    pub async fn advance_time(wallet: &WalletUnlocked, seconds: i64) {
+        let provider = wallet.try_provider().unwrap();
+        let latest_block_time = provider.latest_block_time().await.unwrap().unwrap();
+        provider
+            .produce_blocks(1, Some(latest_block_time + Duration::seconds(seconds)))
+            .await
+            .unwrap();
+    }
 }
@@ -0,0 +1,78 @@
+contract;
+
+use admin::roles::*;
+use ownership::*;
+use src5::{SRC5, State};
+
+abi RolesTest {
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity);
+    #[storage(read, write)]
+    fn revoke_role(role: b256, account: Identity);
+    #[storage(read, write)]
+    fn renounce_role(role: b256);
+    #[storage(read)]
+    fn has_role(role: b256, account: Identity) -> bool;
+    #[storage(read)]
+    fn only_role(role: b256);
+    #[storage(read)]
+    fn role_admin(role: b256) -> b256;
+    #[storage(read, write)]
+    fn set_role_admin(role: b256, new_admin_role: b256);
+}
+
+abi OwnableTest {
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity);
+}
+
+impl RolesTest for Contract {
+    #[storage(read, write)]
+    fn grant_role(role: b256, account: Identity) {
+        grant_role(role, account);
+    }
+
+    #[storage(read, write)]
+    fn revoke_role(role: b256, account: Identity) {
+        revoke_role(role, account);
+    }
+
+    #[storage(read, write)]
+    fn renounce_role(role: b256) {
+        renounce_role(role);
+    }
+
+    #[storage(read)]
+    fn has_role(role: b256, account: Identity) -> bool {
+        has_role(role, account)
+    }
+
+    #[storage(read)]
+    fn only_role(role: b256) {
+        only_role(role);
+    }
+
+    #[storage(read)]
+    fn role_admin(role: b256) -> b256 {
+        _role_admin(role)
+    }
+
+    #[storage(read, write)]
+    fn set_role_admin(role: b256, new_admin_role: b256) {
+        set_role_admin(role, new_admin_role);
+    }
+}
+
+impl OwnableTest for Contract {
+    #[storage(read, write)]
+    fn set_ownership(new_owner: Identity) {
+        initialize_ownership(new_owner);
+    }
+}
+
+impl SRC5 for Contract {
+    #[storage(read)]
+    fn owner() -> State {
+        _owner()
+    }
+}
@@ -0,0 +1,183 @@
+use crate::roles::tests::utils::{
+    abi_calls::{grant_role, has_role, set_ownership, set_role_admin},
+    test_helpers::setup,
+    RoleGranted, DEFAULT_ADMIN_ROLE, MINTER_ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE,
+};
+use fuels::types::{ContractId, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_grants_role() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        assert!(!has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+        let response = grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+        assert!(has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<RoleGranted>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            RoleGranted {
+                role: MINTER_ROLE,
+                account: user1_identity,
+                sender: owner_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn owner_grants_multiple_roles() {
+        let (_deployer, owner, user1, user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let user2_identity = Identity::Address(user2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+        grant_role(&owner.contract, PAUSER_ROLE, user2_identity.clone()).await;
+
+        assert!(has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+        assert!(!has_role(&owner.contract, PAUSER_ROLE, user1_identity.clone()).await);
+        assert!(has_role(&owner.contract, PAUSER_ROLE, user2_identity.clone()).await);
+        assert!(!has_role(&owner.contract, MINTER_ROLE, user2_identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn default_admin_grants_role() {
+        let (_deployer, owner, user1, user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let user2_identity = Identity::Address(user2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, DEFAULT_ADMIN_ROLE, user1_identity.clone()).await;
+
+        let response = grant_role(&user1.contract, MINTER_ROLE, user2_identity.clone()).await;
+        assert!(has_role(&owner.contract, MINTER_ROLE, user2_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<RoleGranted>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            RoleGranted {
+                role: MINTER_ROLE,
+                account: user2_identity,
+                sender: user1_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn role_admin_grants_role() {
+        let (_deployer, owner, user1, user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let user2_identity = Identity::Address(user2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        set_role_admin(&owner.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+        grant_role(&owner.contract, MINTER_ADMIN_ROLE, user1_identity.clone()).await;
+
+        grant_role(&user1.contract, MINTER_ROLE, user2_identity.clone()).await;
+        assert!(has_role(&owner.contract, MINTER_ROLE, user2_identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn grants_role_to_contract_with_same_bits_as_address() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let contract_identity =
+            Identity::ContractId(ContractId::new(*user1.wallet.address().hash()));
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        assert!(!has_role(&owner.contract, MINTER_ROLE, contract_identity.clone()).await);
+        let response = grant_role(&owner.contract, MINTER_ROLE, contract_identity.clone()).await;
+        assert!(has_role(&owner.contract, MINTER_ROLE, contract_identity.clone()).await);
+        assert!(has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<RoleGranted>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            RoleGranted {
+                role: MINTER_ROLE,
+                account: contract_identity,
+                sender: owner_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_log_when_already_granted() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        let response = grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+        assert!(has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<RoleGranted>().unwrap();
+        assert!(log.is_empty());
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRoleAdmin")]
+    async fn when_caller_is_not_owner_or_role_admin() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        grant_role(&user1.contract, MINTER_ROLE, user1_identity.clone()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRoleAdmin")]
+    async fn when_caller_has_role_but_not_admin_role() {
+        let (_deployer, owner, user1, user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let user2_identity = Identity::Address(user2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        grant_role(&user1.contract, MINTER_ROLE, user2_identity.clone()).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRoleAdmin")]
+    async fn when_default_admin_after_role_admin_changed() {
+        let (_deployer, owner, user1, user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let user2_identity = Identity::Address(user2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, DEFAULT_ADMIN_ROLE, user1_identity.clone()).await;
+        set_role_admin(&owner.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+
+        grant_role(&user1.contract, MINTER_ROLE, user2_identity.clone()).await;
+    }
+}
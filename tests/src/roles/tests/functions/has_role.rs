@@ -0,0 +1,43 @@
+use crate::roles::tests::utils::{
+    abi_calls::{grant_role, has_role, set_ownership},
+    test_helpers::setup,
+    DEFAULT_ADMIN_ROLE, MINTER_ROLE,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_false_when_no_role() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+
+        assert!(!has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn returns_true_when_role_granted() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        assert!(has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn owner_does_not_implicitly_have_role() {
+        let (_deployer, owner, _user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        assert!(!has_role(&owner.contract, DEFAULT_ADMIN_ROLE, owner_identity.clone()).await);
+        assert!(!has_role(&owner.contract, MINTER_ROLE, owner_identity.clone()).await);
+    }
+}
@@ -0,0 +1,7 @@
+mod grant_role;
+mod has_role;
+mod only_role;
+mod renounce_role;
+mod revoke_role;
+mod role_admin;
+mod set_role_admin;
@@ -0,0 +1,74 @@
+use crate::roles::tests::utils::{
+    abi_calls::{grant_role, only_role, revoke_role, set_ownership},
+    test_helpers::setup,
+    MINTER_ROLE, PAUSER_ROLE,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn only_role_may_call() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        only_role(&user1.contract, MINTER_ROLE).await;
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_no_role_set() {
+        let (_deployer, _owner, user1, _user2) = setup().await;
+
+        only_role(&user1.contract, MINTER_ROLE).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_owner() {
+        let (_deployer, owner, _user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        only_role(&owner.contract, MINTER_ROLE).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_different_role() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, PAUSER_ROLE, user1_identity.clone()).await;
+
+        only_role(&user1.contract, MINTER_ROLE).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "MissingRole")]
+    async fn when_role_revoked() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+        revoke_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        only_role(&user1.contract, MINTER_ROLE).await;
+    }
+}
@@ -0,0 +1,63 @@
+use crate::roles::tests::utils::{
+    abi_calls::{grant_role, has_role, renounce_role, set_ownership},
+    test_helpers::setup,
+    RoleRevoked, MINTER_ROLE,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn renounces_role() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        assert!(has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+        let response = renounce_role(&user1.contract, MINTER_ROLE).await;
+        assert!(!has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<RoleRevoked>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            RoleRevoked {
+                role: MINTER_ROLE,
+                account: user1_identity.clone(),
+                sender: user1_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn renounces_only_own_role() {
+        let (_deployer, owner, user1, user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let user2_identity = Identity::Address(user2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user2_identity.clone()).await;
+
+        renounce_role(&user1.contract, MINTER_ROLE).await;
+
+        assert!(!has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+        assert!(has_role(&owner.contract, MINTER_ROLE, user2_identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn does_not_log_when_role_not_held() {
+        let (_deployer, _owner, user1, _user2) = setup().await;
+
+        let response = renounce_role(&user1.contract, MINTER_ROLE).await;
+
+        let log = response.decode_logs_with_type::<RoleRevoked>().unwrap();
+        assert!(log.is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::roles::tests::utils::{
+    abi_calls::{grant_role, has_role, revoke_role, set_ownership},
+    test_helpers::setup,
+    RoleRevoked, DEFAULT_ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE,
+};
+use fuels::types::{ContractId, Identity};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_revokes_role() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        assert!(has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+        let response = revoke_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+        assert!(!has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+
+        let log = response.decode_logs_with_type::<RoleRevoked>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            RoleRevoked {
+                role: MINTER_ROLE,
+                account: user1_identity,
+                sender: owner_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn revokes_only_given_identity_with_same_bits() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let contract_identity =
+            Identity::ContractId(ContractId::new(*user1.wallet.address().hash()));
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, contract_identity.clone()).await;
+
+        revoke_role(&owner.contract, MINTER_ROLE, contract_identity.clone()).await;
+
+        assert!(!has_role(&owner.contract, MINTER_ROLE, contract_identity.clone()).await);
+        assert!(has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn revokes_only_given_role() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+        grant_role(&owner.contract, PAUSER_ROLE, user1_identity.clone()).await;
+
+        revoke_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        assert!(!has_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await);
+        assert!(has_role(&owner.contract, PAUSER_ROLE, user1_identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn default_admin_revokes_role() {
+        let (_deployer, owner, user1, user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        let user2_identity = Identity::Address(user2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, DEFAULT_ADMIN_ROLE, user1_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user2_identity.clone()).await;
+
+        revoke_role(&user1.contract, MINTER_ROLE, user2_identity.clone()).await;
+        assert!(!has_role(&owner.contract, MINTER_ROLE, user2_identity.clone()).await);
+    }
+
+    #[tokio::test]
+    async fn does_not_log_when_role_not_held() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        let response = revoke_role(&owner.contract, MINTER_ROLE, user1_identity.clone()).await;
+
+        let log = response.decode_logs_with_type::<RoleRevoked>().unwrap();
+        assert!(log.is_empty());
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotRoleAdmin")]
+    async fn when_caller_is_not_owner_or_role_admin() {
+        let (_deployer, owner, user1, user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user2_identity = Identity::Address(user2.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, MINTER_ROLE, user2_identity.clone()).await;
+
+        revoke_role(&user1.contract, MINTER_ROLE, user2_identity.clone()).await;
+    }
+}
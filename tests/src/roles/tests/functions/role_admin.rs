@@ -0,0 +1,43 @@
+use crate::roles::tests::utils::{
+    abi_calls::{role_admin, set_ownership, set_role_admin},
+    test_helpers::setup,
+    DEFAULT_ADMIN_ROLE, MINTER_ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_default_admin_role() {
+        let (_deployer, owner, _user1, _user2) = setup().await;
+
+        assert_eq!(
+            role_admin(&owner.contract, MINTER_ROLE).await,
+            DEFAULT_ADMIN_ROLE
+        );
+        assert_eq!(
+            role_admin(&owner.contract, PAUSER_ROLE).await,
+            DEFAULT_ADMIN_ROLE
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_set_admin_role() {
+        let (_deployer, owner, _user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        set_role_admin(&owner.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+
+        assert_eq!(
+            role_admin(&owner.contract, MINTER_ROLE).await,
+            MINTER_ADMIN_ROLE
+        );
+        assert_eq!(
+            role_admin(&owner.contract, PAUSER_ROLE).await,
+            DEFAULT_ADMIN_ROLE
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::roles::tests::utils::{
+    abi_calls::{grant_role, role_admin, set_ownership, set_role_admin},
+    test_helpers::setup,
+    RoleAdminChanged, DEFAULT_ADMIN_ROLE, MINTER_ADMIN_ROLE, MINTER_ROLE, PAUSER_ROLE,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_role_admin() {
+        let (_deployer, owner, _user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        let response = set_role_admin(&owner.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+        assert_eq!(
+            role_admin(&owner.contract, MINTER_ROLE).await,
+            MINTER_ADMIN_ROLE
+        );
+
+        let log = response
+            .decode_logs_with_type::<RoleAdminChanged>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            RoleAdminChanged {
+                role: MINTER_ROLE,
+                previous_admin_role: DEFAULT_ADMIN_ROLE,
+                new_admin_role: MINTER_ADMIN_ROLE,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_role_admin_twice() {
+        let (_deployer, owner, _user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        set_role_admin(&owner.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+
+        let response = set_role_admin(&owner.contract, MINTER_ROLE, PAUSER_ROLE).await;
+        assert_eq!(role_admin(&owner.contract, MINTER_ROLE).await, PAUSER_ROLE);
+
+        let log = response
+            .decode_logs_with_type::<RoleAdminChanged>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            RoleAdminChanged {
+                role: MINTER_ROLE,
+                previous_admin_role: MINTER_ADMIN_ROLE,
+                new_admin_role: PAUSER_ROLE,
+            }
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_caller_is_not_owner() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+
+        set_role_admin(&user1.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_caller_is_default_admin() {
+        let (_deployer, owner, user1, _user2) = setup().await;
+
+        let owner_identity = Identity::Address(owner.wallet.address().into());
+        let user1_identity = Identity::Address(user1.wallet.address().into());
+        set_ownership(&owner.contract, owner_identity.clone()).await;
+        grant_role(&owner.contract, DEFAULT_ADMIN_ROLE, user1_identity.clone()).await;
+
+        set_role_admin(&user1.contract, MINTER_ROLE, MINTER_ADMIN_ROLE).await;
+    }
+}
@@ -0,0 +1,171 @@
+use fuels::{
+    prelude::{
+        abigen, launch_custom_provider_and_get_wallets, Contract, LoadConfiguration,
+        StorageConfiguration, TxPolicies, WalletUnlocked, WalletsConfig,
+    },
+    programs::responses::CallResponse,
+    types::{Bits256, Identity},
+};
+
+// Load abi from json
+abigen!(Contract(
+    name = "RolesLib",
+    abi = "src/roles/out/release/roles_test-abi.json"
+));
+
+pub const DEFAULT_ADMIN_ROLE: Bits256 = Bits256([0u8; 32]);
+pub const MINTER_ROLE: Bits256 = Bits256([1u8; 32]);
+pub const PAUSER_ROLE: Bits256 = Bits256([2u8; 32]);
+pub const MINTER_ADMIN_ROLE: Bits256 = Bits256([3u8; 32]);
+
+pub struct Metadata {
+    pub contract: RolesLib<WalletUnlocked>,
+    pub wallet: WalletUnlocked,
+}
+
+pub mod abi_calls {
+
+    use super::*;
+
+    pub async fn grant_role(
+        contract: &RolesLib<WalletUnlocked>,
+        role: Bits256,
+        account: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .grant_role(role, account)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn revoke_role(
+        contract: &RolesLib<WalletUnlocked>,
+        role: Bits256,
+        account: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .revoke_role(role, account)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn renounce_role(
+        contract: &RolesLib<WalletUnlocked>,
+        role: Bits256,
+    ) -> CallResponse<()> {
+        contract.methods().renounce_role(role).call().await.unwrap()
+    }
+
+    pub async fn has_role(
+        contract: &RolesLib<WalletUnlocked>,
+        role: Bits256,
+        account: Identity,
+    ) -> bool {
+        contract
+            .methods()
+            .has_role(role, account)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn only_role(contract: &RolesLib<WalletUnlocked>, role: Bits256) -> CallResponse<()> {
+        contract.methods().only_role(role).call().await.unwrap()
+    }
+
+    pub async fn role_admin(contract: &RolesLib<WalletUnlocked>, role: Bits256) -> Bits256 {
+        contract
+            .methods()
+            .role_admin(role)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn set_role_admin(
+        contract: &RolesLib<WalletUnlocked>,
+        role: Bits256,
+        new_admin_role: Bits256,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .set_role_admin(role, new_admin_role)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn set_ownership(
+        contract: &RolesLib<WalletUnlocked>,
+        new_owner: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .set_ownership(new_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+}
+
+pub mod test_helpers {
+
+    use super::*;
+
+    pub async fn setup() -> (Metadata, Metadata, Metadata, Metadata) {
+        let num_wallets = 5;
+        let coins_per_wallet = 1;
+        let coin_amount = 1000000;
+        let mut wallets = launch_custom_provider_and_get_wallets(
+            WalletsConfig::new(Some(num_wallets), Some(coins_per_wallet), Some(coin_amount)),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Get the wallets from that provider
+        let wallet1 = wallets.pop().unwrap();
+        let wallet2 = wallets.pop().unwrap();
+        let wallet3 = wallets.pop().unwrap();
+        let wallet4 = wallets.pop().unwrap();
+
+        let storage_configuration = StorageConfiguration::default()
+            .add_slot_overrides_from_file("src/roles/out/release/roles_test-storage_slots.json");
+        let configuration =
+            LoadConfiguration::default().with_storage_configuration(storage_configuration.unwrap());
+        let id = Contract::load_from("src/roles/out/release/roles_test.bin", configuration)
+            .unwrap()
+            .deploy(&wallet1, TxPolicies::default())
+            .await
+            .unwrap();
+
+        let deploy_wallet = Metadata {
+            contract: RolesLib::new(id.clone(), wallet1.clone()),
+            wallet: wallet1.clone(),
+        };
+
+        let owner = Metadata {
+            contract: RolesLib::new(id.clone(), wallet2.clone()),
+            wallet: wallet2.clone(),
+        };
+
+        let user1 = Metadata {
+            contract: RolesLib::new(id.clone(), wallet3.clone()),
+            wallet: wallet3.clone(),
+        };
+
+        let user2 = Metadata {
+            contract: RolesLib::new(id.clone(), wallet4.clone()),
+            wallet: wallet4.clone(),
+        };
+
+        (deploy_wallet, owner, user1, user2)
+    }
+}
@@ -1,11 +1,37 @@
 contract;
 
 use upgradability::{
+    _pending_proxy_owner,
     _proxy_owner,
     _proxy_target,
+    _proxy_target_history,
+    _proxy_target_history_len,
     _set_proxy_owner,
     _set_proxy_target,
+    accept_proxy_owner,
+    initialize_proxy_owner,
     only_proxy_owner,
+    propose_proxy_owner,
+    renounce_proxy_owner,
+    rollback_proxy_target,
+};
+use upgradability::timelock::{
+    _pending_proxy_target,
+    _proxy_delay,
+    _scheduled_proxy_owner,
+    cancel_proxy_target,
+    cancel_scheduled_proxy_owner,
+    execute_proxy_owner,
+    execute_proxy_target,
+    initialize_proxy_delay,
+    schedule_proxy_owner,
+    schedule_proxy_target,
+};
+use upgradability::verification::{
+    _is_implementation_root_approved,
+    _set_proxy_target_verified,
+    approve_implementation_root,
+    revoke_implementation_root,
 };
 use src14::{SRC14, SRC14Extension};
 use src5::State;
@@ -41,6 +67,72 @@ abi UpgradableTest {
 
     #[storage(write)]
     fn initialize_proxy();
+
+    #[storage(read)]
+    fn proxy_delay() -> Option<u64>;
+
+    #[storage(read, write)]
+    fn initialize_proxy_delay(min_delay: u64);
+
+    #[storage(read)]
+    fn scheduled_proxy_owner() -> Option<(State, u64)>;
+
+    #[storage(read, write)]
+    fn schedule_proxy_owner(new_proxy_owner: State, delay: u64);
+
+    #[storage(read, write)]
+    fn execute_proxy_owner();
+
+    #[storage(read, write)]
+    fn cancel_scheduled_proxy_owner();
+
+    #[storage(read)]
+    fn pending_proxy_target() -> Option<(ContractId, u64)>;
+
+    #[storage(read, write)]
+    fn schedule_proxy_target(new_target: ContractId, eta: u64);
+
+    #[storage(read, write)]
+    fn execute_proxy_target();
+
+    #[storage(read, write)]
+    fn cancel_proxy_target();
+
+    #[storage(read)]
+    fn is_implementation_root_approved(root: b256) -> bool;
+
+    #[storage(read, write)]
+    fn approve_implementation_root(root: b256);
+
+    #[storage(read, write)]
+    fn revoke_implementation_root(root: b256);
+
+    #[storage(read, write)]
+    fn set_proxy_target_verified(new_target: ContractId, expected_root: b256);
+
+    #[storage(read)]
+    fn proxy_target_history(index: u64) -> Option<(ContractId, u32)>;
+
+    #[storage(read)]
+    fn proxy_target_history_len() -> u64;
+
+    #[storage(read, write)]
+    fn rollback_proxy_target();
+
+    #[storage(read, write)]
+    fn renounce_proxy_owner();
+
+    #[storage(read)]
+    fn pending_proxy_owner() -> Option<Identity>;
+
+    #[storage(read, write)]
+    fn propose_proxy_owner(new_proxy_owner: Identity);
+
+    #[storage(read, write)]
+    fn accept_proxy_owner();
+
+    #[storage(read, write)]
+    fn initialize_proxy_owner(new_proxy_owner: Identity);
 }
 
 impl SRC14 for Contract {
@@ -79,4 +171,114 @@ impl UpgradableTest for Contract {
         storage::SRC14.target.write(INITIAL_TARGET);
         storage::SRC14.proxy_owner.write(INITIAL_OWNER);
     }
+
+    #[storage(read)]
+    fn proxy_delay() -> Option<u64> {
+        _proxy_delay()
+    }
+
+    #[storage(read, write)]
+    fn initialize_proxy_delay(min_delay: u64) {
+        initialize_proxy_delay(min_delay);
+    }
+
+    #[storage(read)]
+    fn scheduled_proxy_owner() -> Option<(State, u64)> {
+        _scheduled_proxy_owner()
+    }
+
+    #[storage(read, write)]
+    fn schedule_proxy_owner(new_proxy_owner: State, delay: u64) {
+        schedule_proxy_owner(new_proxy_owner, delay);
+    }
+
+    #[storage(read, write)]
+    fn execute_proxy_owner() {
+        execute_proxy_owner();
+    }
+
+    #[storage(read, write)]
+    fn cancel_scheduled_proxy_owner() {
+        cancel_scheduled_proxy_owner();
+    }
+
+    #[storage(read)]
+    fn pending_proxy_target() -> Option<(ContractId, u64)> {
+        _pending_proxy_target()
+    }
+
+    #[storage(read, write)]
+    fn schedule_proxy_target(new_target: ContractId, eta: u64) {
+        schedule_proxy_target(new_target, eta);
+    }
+
+    #[storage(read, write)]
+    fn execute_proxy_target() {
+        execute_proxy_target();
+    }
+
+    #[storage(read, write)]
+    fn cancel_proxy_target() {
+        cancel_proxy_target();
+    }
+
+    #[storage(read)]
+    fn is_implementation_root_approved(root: b256) -> bool {
+        _is_implementation_root_approved(root)
+    }
+
+    #[storage(read, write)]
+    fn approve_implementation_root(root: b256) {
+        approve_implementation_root(root);
+    }
+
+    #[storage(read, write)]
+    fn revoke_implementation_root(root: b256) {
+        revoke_implementation_root(root);
+    }
+
+    #[storage(read, write)]
+    fn set_proxy_target_verified(new_target: ContractId, expected_root: b256) {
+        _set_proxy_target_verified(new_target, expected_root);
+    }
+
+    #[storage(read)]
+    fn proxy_target_history(index: u64) -> Option<(ContractId, u32)> {
+        _proxy_target_history(index)
+    }
+
+    #[storage(read)]
+    fn proxy_target_history_len() -> u64 {
+        _proxy_target_history_len()
+    }
+
+    #[storage(read, write)]
+    fn rollback_proxy_target() {
+        rollback_proxy_target();
+    }
+
+    #[storage(read, write)]
+    fn renounce_proxy_owner() {
+        renounce_proxy_owner();
+    }
+
+    #[storage(read)]
+    fn pending_proxy_owner() -> Option<Identity> {
+        _pending_proxy_owner()
+    }
+
+    #[storage(read, write)]
+    fn propose_proxy_owner(new_proxy_owner: Identity) {
+        propose_proxy_owner(new_proxy_owner);
+    }
+
+    #[storage(read, write)]
+    fn accept_proxy_owner() {
+        accept_proxy_owner();
+    }
+
+    #[storage(read, write)]
+    fn initialize_proxy_owner(new_proxy_owner: Identity) {
+        initialize_proxy_owner(new_proxy_owner);
+    }
 }
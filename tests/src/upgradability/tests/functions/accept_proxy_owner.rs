@@ -0,0 +1,95 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        accept_proxy_owner, initialize_proxy_delay, only_proxy_owner, pending_proxy_owner,
+        propose_proxy_owner, proxy_owner, schedule_proxy_owner, scheduled_proxy_owner,
+    },
+    test_helpers::setup,
+    ProxyOwnerSet, State,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn accepts_proxy_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        propose_proxy_owner(&owner1.contract, owner2_identity.clone()).await;
+
+        let response = accept_proxy_owner(&owner2.contract).await;
+
+        assert_eq!(
+            proxy_owner(&owner1.contract).await.value,
+            State::Initialized(owner2_identity.clone())
+        );
+        assert_eq!(pending_proxy_owner(&owner1.contract).await, None);
+        only_proxy_owner(&owner2.contract).await;
+
+        let log = response.decode_logs_with_type::<ProxyOwnerSet>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            ProxyOwnerSet {
+                new_proxy_owner: State::Initialized(owner2_identity),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn clears_scheduled_proxy_owner() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        initialize_proxy_delay(&owner1.contract, 100).await;
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(deployer.wallet.address().into()),
+            100,
+        )
+        .await;
+        propose_proxy_owner(&owner1.contract, owner2_identity).await;
+
+        accept_proxy_owner(&owner2.contract).await;
+
+        assert_eq!(scheduled_proxy_owner(&owner1.contract).await, None);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingProxyOwner")]
+    async fn when_not_proposed() {
+        let (_deployer, _owner1, owner2) = setup().await;
+
+        accept_proxy_owner(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotPendingProxyOwner")]
+    async fn when_called_by_other_identity() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        propose_proxy_owner(&owner1.contract, owner2_identity).await;
+
+        accept_proxy_owner(&deployer.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn previous_owner_loses_access() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        propose_proxy_owner(&owner1.contract, owner2_identity).await;
+        accept_proxy_owner(&owner2.contract).await;
+
+        only_proxy_owner(&owner1.contract).await;
+    }
+}
@@ -0,0 +1,42 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{approve_implementation_root, is_implementation_root_approved},
+    test_helpers::{deploy_implementation, setup, CORRECT_IMPLEMENTATION_PATH},
+    ImplementationRootApproved,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn approves_root() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let (_id, root) = deploy_implementation(&owner1.wallet, CORRECT_IMPLEMENTATION_PATH).await;
+
+        assert!(!is_implementation_root_approved(&owner1.contract, root).await);
+
+        let response = approve_implementation_root(&owner1.contract, root).await;
+
+        assert!(is_implementation_root_approved(&owner1.contract, root).await);
+
+        let log = response
+            .decode_logs_with_type::<ImplementationRootApproved>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(*event, ImplementationRootApproved { root });
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, _owner1, owner2) = setup().await;
+        let (_id, root) = deploy_implementation(&owner2.wallet, CORRECT_IMPLEMENTATION_PATH).await;
+
+        approve_implementation_root(&owner2.contract, root).await;
+    }
+}
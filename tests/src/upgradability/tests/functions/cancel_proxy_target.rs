@@ -0,0 +1,65 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        cancel_proxy_target, initialize_proxy_delay, pending_proxy_target, proxy_target,
+        schedule_proxy_target,
+    },
+    test_helpers::{block_timestamp, setup, INITIAL_TARGET, SECOND_TARGET},
+    ScheduledProxyTargetCancelled,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancels_scheduled_target() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+        let response = cancel_proxy_target(&owner1.contract).await;
+
+        assert_eq!(pending_proxy_target(&owner1.contract).await, None);
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(INITIAL_TARGET)
+        );
+
+        let log = response
+            .decode_logs_with_type::<ScheduledProxyTargetCancelled>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            ScheduledProxyTargetCancelled {
+                new_target: SECOND_TARGET,
+            }
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_not_scheduled() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        cancel_proxy_target(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+
+        cancel_proxy_target(&owner2.contract).await;
+    }
+}
@@ -0,0 +1,78 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        cancel_scheduled_proxy_owner, execute_proxy_owner, initialize_proxy_delay,
+        schedule_proxy_owner, scheduled_proxy_owner,
+    },
+    test_helpers::{advance_time, setup},
+    ScheduledProxyOwnerCancelled, State,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancels_scheduled_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        let new_proxy_owner = State::Initialized(owner2.wallet.address().into());
+        schedule_proxy_owner(&owner1.contract, new_proxy_owner.clone(), 100).await;
+
+        let response = cancel_scheduled_proxy_owner(&owner1.contract).await;
+        assert_eq!(scheduled_proxy_owner(&owner1.contract).await, None);
+
+        let log = response
+            .decode_logs_with_type::<ScheduledProxyOwnerCancelled>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(*event, ScheduledProxyOwnerCancelled { new_proxy_owner });
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            100,
+        )
+        .await;
+
+        cancel_scheduled_proxy_owner(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_not_scheduled() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        cancel_scheduled_proxy_owner(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_executing_cancelled_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            100,
+        )
+        .await;
+        cancel_scheduled_proxy_owner(&owner1.contract).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        execute_proxy_owner(&owner1.contract).await;
+    }
+}
@@ -0,0 +1,106 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        execute_proxy_owner, initialize_proxy_delay, proxy_owner, schedule_proxy_owner,
+        scheduled_proxy_owner,
+    },
+    test_helpers::{advance_time, setup},
+    ProxyOwnerSet, ScheduledProxyOwnerExecuted, State,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn executes_after_delay() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        let new_proxy_owner = State::Initialized(owner2.wallet.address().into());
+        schedule_proxy_owner(&owner1.contract, new_proxy_owner.clone(), 100).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        let response = execute_proxy_owner(&owner1.contract).await;
+
+        assert_eq!(
+            proxy_owner(&owner1.contract).await.value,
+            new_proxy_owner.clone()
+        );
+        assert_eq!(scheduled_proxy_owner(&owner1.contract).await, None);
+
+        let executed_log = response
+            .decode_logs_with_type::<ScheduledProxyOwnerExecuted>()
+            .unwrap();
+        let executed_event = executed_log.first().unwrap();
+        assert_eq!(
+            *executed_event,
+            ScheduledProxyOwnerExecuted {
+                new_proxy_owner: new_proxy_owner.clone(),
+            }
+        );
+
+        let set_log = response.decode_logs_with_type::<ProxyOwnerSet>().unwrap();
+        let set_event = set_log.first().unwrap();
+        assert_eq!(*set_event, ProxyOwnerSet { new_proxy_owner });
+    }
+
+    #[tokio::test]
+    async fn executes_revoked_ownership() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(&owner1.contract, State::Revoked, 100).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        execute_proxy_owner(&owner1.contract).await;
+
+        assert_eq!(proxy_owner(&owner1.contract).await.value, State::Revoked);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotElapsed")]
+    async fn when_delay_not_elapsed() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            1000,
+        )
+        .await;
+
+        advance_time(&owner1.wallet, 10).await;
+        execute_proxy_owner(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_not_scheduled() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        execute_proxy_owner(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            100,
+        )
+        .await;
+
+        advance_time(&owner1.wallet, 101).await;
+        execute_proxy_owner(&owner2.contract).await;
+    }
+}
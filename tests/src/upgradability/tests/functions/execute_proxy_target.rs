@@ -0,0 +1,104 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        execute_proxy_target, initialize_proxy_delay, pending_proxy_target, proxy_target,
+        schedule_proxy_target,
+    },
+    test_helpers::{advance_time, block_timestamp, setup, SECOND_TARGET},
+    ProxyTargetSet, ScheduledProxyTargetExecuted,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn executes_after_eta() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        let response = execute_proxy_target(&owner1.contract).await;
+
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(SECOND_TARGET)
+        );
+        assert_eq!(pending_proxy_target(&owner1.contract).await, None);
+
+        let executed_log = response
+            .decode_logs_with_type::<ScheduledProxyTargetExecuted>()
+            .unwrap();
+        let executed_event = executed_log.first().unwrap();
+        assert_eq!(
+            *executed_event,
+            ScheduledProxyTargetExecuted {
+                new_target: SECOND_TARGET,
+            }
+        );
+
+        let set_log = response.decode_logs_with_type::<ProxyTargetSet>().unwrap();
+        let set_event = set_log.first().unwrap();
+        assert_eq!(
+            *set_event,
+            ProxyTargetSet {
+                new_target: SECOND_TARGET,
+            }
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotElapsed")]
+    async fn when_eta_not_reached() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 1000;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+
+        advance_time(&owner1.wallet, 10).await;
+        execute_proxy_target(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_not_scheduled() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        execute_proxy_target(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotScheduled")]
+    async fn when_executed_twice() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        execute_proxy_target(&owner1.contract).await;
+        execute_proxy_target(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+
+        advance_time(&owner1.wallet, 101).await;
+        execute_proxy_target(&owner2.contract).await;
+    }
+}
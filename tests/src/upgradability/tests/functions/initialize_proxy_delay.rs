@@ -0,0 +1,55 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{initialize_proxy_delay, proxy_delay},
+    test_helpers::setup,
+    ProxyDelaySet,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn initializes_proxy_delay() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        assert_eq!(proxy_delay(&owner1.contract).await, None);
+
+        let response = initialize_proxy_delay(&owner1.contract, 100).await;
+
+        assert_eq!(proxy_delay(&owner1.contract).await, Some(100));
+
+        let log = response.decode_logs_with_type::<ProxyDelaySet>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(*event, ProxyDelaySet { min_delay: 100 });
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, _owner1, owner2) = setup().await;
+
+        initialize_proxy_delay(&owner2.contract, 100).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayAlreadyInitialized")]
+    async fn when_already_initialized() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        initialize_proxy_delay(&owner1.contract, 1).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayTooShort")]
+    async fn when_zero() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        initialize_proxy_delay(&owner1.contract, 0).await;
+    }
+}
@@ -0,0 +1,75 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{initialize_proxy_owner, only_proxy_owner, proxy_owner, renounce_proxy_owner},
+    test_helpers::{setup, setup_uninitialized},
+    ProxyOwnerSet, State,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn initializes_proxy_owner() {
+        let (_deployer, owner1, _owner2) = setup_uninitialized().await;
+
+        assert_eq!(
+            proxy_owner(&owner1.contract).await.value,
+            State::Uninitialized
+        );
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let response = initialize_proxy_owner(&owner1.contract, owner1_identity.clone()).await;
+
+        assert_eq!(
+            proxy_owner(&owner1.contract).await.value,
+            State::Initialized(owner1_identity.clone())
+        );
+        only_proxy_owner(&owner1.contract).await;
+
+        let log = response.decode_logs_with_type::<ProxyOwnerSet>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            ProxyOwnerSet {
+                new_proxy_owner: State::Initialized(owner1_identity),
+            }
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotReinitialized")]
+    async fn when_initialized_twice() {
+        let (_deployer, owner1, owner2) = setup_uninitialized().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        initialize_proxy_owner(&owner1.contract, owner1_identity).await;
+        initialize_proxy_owner(&owner2.contract, owner2_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotReinitialized")]
+    async fn when_owner_set_in_storage() {
+        let (_deployer, _owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        initialize_proxy_owner(&owner2.contract, owner2_identity).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotReinitialized")]
+    async fn when_proxy_owner_renounced() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        renounce_proxy_owner(&owner1.contract).await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        initialize_proxy_owner(&owner2.contract, owner2_identity).await;
+    }
+}
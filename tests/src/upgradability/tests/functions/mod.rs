@@ -1,5 +1,24 @@
+mod accept_proxy_owner;
+mod approve_implementation_root;
+mod cancel_proxy_target;
+mod cancel_scheduled_proxy_owner;
+mod execute_proxy_owner;
+mod execute_proxy_target;
+mod initialize_proxy_delay;
+mod initialize_proxy_owner;
 mod only_proxy_owner;
+mod pending_proxy_owner;
+mod pending_proxy_target;
+mod propose_proxy_owner;
 mod proxy_owner;
 mod proxy_target;
+mod proxy_target_history;
+mod proxy_target_history_len;
+mod renounce_proxy_owner;
+mod revoke_implementation_root;
+mod rollback_proxy_target;
+mod schedule_proxy_owner;
+mod schedule_proxy_target;
 mod set_proxy_owner;
 mod set_proxy_target;
+mod set_proxy_target_verified;
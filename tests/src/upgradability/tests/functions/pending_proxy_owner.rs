@@ -0,0 +1,41 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{accept_proxy_owner, pending_proxy_owner, propose_proxy_owner},
+    test_helpers::setup,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_not_proposed() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        assert_eq!(pending_proxy_owner(&owner1.contract).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_proposed_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        propose_proxy_owner(&owner1.contract, owner2_identity.clone()).await;
+
+        assert_eq!(
+            pending_proxy_owner(&owner1.contract).await,
+            Some(owner2_identity)
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_after_accept() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        propose_proxy_owner(&owner1.contract, owner2_identity).await;
+        accept_proxy_owner(&owner2.contract).await;
+
+        assert_eq!(pending_proxy_owner(&owner1.contract).await, None);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        cancel_proxy_target, initialize_proxy_delay, pending_proxy_target, schedule_proxy_target,
+    },
+    test_helpers::{block_timestamp, setup, SECOND_TARGET},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_not_scheduled() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        assert_eq!(pending_proxy_target(&owner1.contract).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_scheduled_target() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+
+        assert_eq!(
+            pending_proxy_target(&owner2.contract).await,
+            Some((SECOND_TARGET, eta))
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_none_after_cancel() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+        cancel_proxy_target(&owner1.contract).await;
+
+        assert_eq!(pending_proxy_target(&owner1.contract).await, None);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{pending_proxy_owner, propose_proxy_owner, proxy_owner, set_proxy_owner},
+    test_helpers::setup,
+    ProxyOwnerProposed, State,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn proposes_new_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+
+        let response = propose_proxy_owner(&owner1.contract, owner2_identity.clone()).await;
+
+        assert_eq!(
+            pending_proxy_owner(&owner1.contract).await,
+            Some(owner2_identity.clone())
+        );
+        assert_eq!(
+            proxy_owner(&owner1.contract).await.value,
+            State::Initialized(owner1_identity.clone())
+        );
+
+        let log = response
+            .decode_logs_with_type::<ProxyOwnerProposed>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            ProxyOwnerProposed {
+                pending_proxy_owner: owner2_identity,
+                proxy_owner: owner1_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_pending_owner() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+
+        propose_proxy_owner(&owner1.contract, owner2_identity).await;
+        propose_proxy_owner(&owner1.contract, deployer_identity.clone()).await;
+
+        assert_eq!(
+            pending_proxy_owner(&owner1.contract).await,
+            Some(deployer_identity)
+        );
+    }
+
+    #[tokio::test]
+    async fn cleared_by_set_proxy_owner() {
+        let (deployer, owner1, owner2) = setup().await;
+
+        let deployer_identity = Identity::Address(deployer.wallet.address().into());
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+
+        propose_proxy_owner(&owner1.contract, owner2_identity).await;
+        set_proxy_owner(&owner1.contract, State::Initialized(deployer_identity)).await;
+
+        assert_eq!(pending_proxy_owner(&owner1.contract).await, None);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, _owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        propose_proxy_owner(&owner2.contract, owner2_identity).await;
+    }
+}
@@ -2,7 +2,7 @@ mod success {
 
     use crate::upgradability::tests::utils::{
         abi_calls::{proxy_owner, set_proxy_owner},
-        test_helpers::setup,
+        test_helpers::{setup, setup_uninitialized},
         State,
     };
 
@@ -24,4 +24,14 @@ mod success {
 
         assert_eq!(proxy_owner(&owner1.contract).await.value, State::Revoked);
     }
+
+    #[tokio::test]
+    async fn returns_uninitialized_when_unset() {
+        let (_deployer, owner1, _owner2) = setup_uninitialized().await;
+
+        assert_eq!(
+            proxy_owner(&owner1.contract).await.value,
+            State::Uninitialized
+        );
+    }
 }
@@ -0,0 +1,42 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{proxy_target_history, set_proxy_target},
+    test_helpers::{block_height, setup, INITIAL_TARGET, SECOND_TARGET},
+};
+use fuels::types::ContractId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_none_when_empty() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        assert_eq!(proxy_target_history(&owner1.contract, 0).await, None);
+    }
+
+    #[tokio::test]
+    async fn returns_targets_in_order() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let third_target = ContractId::new([2u8; 32]);
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        let first_height = block_height(&owner1.wallet).await;
+        set_proxy_target(&owner1.contract, third_target).await;
+        let second_height = block_height(&owner1.wallet).await;
+
+        assert_eq!(
+            proxy_target_history(&owner1.contract, 0).await,
+            Some((INITIAL_TARGET, 0))
+        );
+        assert_eq!(
+            proxy_target_history(&owner1.contract, 1).await,
+            Some((SECOND_TARGET, first_height))
+        );
+        assert_eq!(
+            proxy_target_history(&owner1.contract, 2).await,
+            Some((third_target, second_height))
+        );
+        assert_eq!(proxy_target_history(&owner1.contract, 3).await, None);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{proxy_target_history_len, rollback_proxy_target, set_proxy_target},
+    test_helpers::{setup, INITIAL_TARGET, SECOND_TARGET},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_zero_when_empty() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 0);
+    }
+
+    #[tokio::test]
+    async fn records_initial_target_with_first_change() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 2);
+    }
+
+    #[tokio::test]
+    async fn increments_with_each_target() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 2);
+
+        set_proxy_target(&owner1.contract, INITIAL_TARGET).await;
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 3);
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 4);
+    }
+
+    #[tokio::test]
+    async fn increments_on_rollback() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 2);
+
+        rollback_proxy_target(&owner1.contract).await;
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 3);
+    }
+}
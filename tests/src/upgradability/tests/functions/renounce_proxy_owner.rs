@@ -0,0 +1,90 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        initialize_proxy_delay, pending_proxy_owner, propose_proxy_owner, proxy_owner,
+        renounce_proxy_owner, schedule_proxy_owner, scheduled_proxy_owner,
+    },
+    test_helpers::setup,
+    ProxyOwnerRenounced, State,
+};
+use fuels::types::Identity;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn renounces_proxy_owner() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let owner1_identity = Identity::Address(owner1.wallet.address().into());
+        assert_eq!(
+            proxy_owner(&owner1.contract).await.value,
+            State::Initialized(owner1_identity.clone())
+        );
+
+        let response = renounce_proxy_owner(&owner1.contract).await;
+
+        assert_eq!(proxy_owner(&owner1.contract).await.value, State::Revoked);
+
+        let log = response
+            .decode_logs_with_type::<ProxyOwnerRenounced>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            ProxyOwnerRenounced {
+                previous_proxy_owner: owner1_identity,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn clears_pending_proxy_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        let owner2_identity = Identity::Address(owner2.wallet.address().into());
+        propose_proxy_owner(&owner1.contract, owner2_identity).await;
+
+        renounce_proxy_owner(&owner1.contract).await;
+
+        assert_eq!(pending_proxy_owner(&owner1.contract).await, None);
+    }
+
+    #[tokio::test]
+    async fn clears_scheduled_proxy_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            100,
+        )
+        .await;
+
+        renounce_proxy_owner(&owner1.contract).await;
+
+        assert_eq!(scheduled_proxy_owner(&owner1.contract).await, None);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, _owner1, owner2) = setup().await;
+
+        renounce_proxy_owner(&owner2.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_renounced_twice() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        renounce_proxy_owner(&owner1.contract).await;
+        renounce_proxy_owner(&owner1.contract).await;
+    }
+}
@@ -0,0 +1,160 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        proxy_target, proxy_target_history, proxy_target_history_len, rollback_proxy_target,
+        set_proxy_target,
+    },
+    test_helpers::{block_height, setup, INITIAL_TARGET, SECOND_TARGET},
+    ProxyTargetRolledBack, ProxyTargetSet,
+};
+use fuels::types::ContractId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn rolls_back_to_previous_target() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let third_target = ContractId::new([2u8; 32]);
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        let second_height = block_height(&owner1.wallet).await;
+        set_proxy_target(&owner1.contract, third_target).await;
+        let third_height = block_height(&owner1.wallet).await;
+
+        let response = rollback_proxy_target(&owner1.contract).await;
+        let rollback_height = block_height(&owner1.wallet).await;
+
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(SECOND_TARGET)
+        );
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 4);
+        assert_eq!(
+            proxy_target_history(&owner1.contract, 1).await,
+            Some((SECOND_TARGET, second_height))
+        );
+        assert_eq!(
+            proxy_target_history(&owner1.contract, 2).await,
+            Some((third_target, third_height))
+        );
+        assert_eq!(
+            proxy_target_history(&owner1.contract, 3).await,
+            Some((SECOND_TARGET, rollback_height))
+        );
+
+        let rolled_back_log = response
+            .decode_logs_with_type::<ProxyTargetRolledBack>()
+            .unwrap();
+        let rolled_back_event = rolled_back_log.first().unwrap();
+        assert_eq!(
+            *rolled_back_event,
+            ProxyTargetRolledBack {
+                previous_target: third_target,
+                new_target: SECOND_TARGET,
+            }
+        );
+
+        let set_log = response.decode_logs_with_type::<ProxyTargetSet>().unwrap();
+        let set_event = set_log.first().unwrap();
+        assert_eq!(
+            *set_event,
+            ProxyTargetSet {
+                new_target: SECOND_TARGET,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn rolls_back_to_initial_target() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        rollback_proxy_target(&owner1.contract).await;
+        let rollback_height = block_height(&owner1.wallet).await;
+
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(INITIAL_TARGET)
+        );
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 3);
+        assert_eq!(
+            proxy_target_history(&owner1.contract, 0).await,
+            Some((INITIAL_TARGET, 0))
+        );
+        assert_eq!(
+            proxy_target_history(&owner1.contract, 2).await,
+            Some((INITIAL_TARGET, rollback_height))
+        );
+    }
+
+    #[tokio::test]
+    async fn rolls_back_after_multiple_upgrades() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let third_target = ContractId::new([2u8; 32]);
+        let fourth_target = ContractId::new([3u8; 32]);
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        set_proxy_target(&owner1.contract, third_target).await;
+        set_proxy_target(&owner1.contract, fourth_target).await;
+
+        rollback_proxy_target(&owner1.contract).await;
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(third_target)
+        );
+
+        set_proxy_target(&owner1.contract, INITIAL_TARGET).await;
+        rollback_proxy_target(&owner1.contract).await;
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(third_target)
+        );
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 7);
+    }
+
+    #[tokio::test]
+    async fn rolling_back_twice_restores_rolled_back_target() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let third_target = ContractId::new([2u8; 32]);
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+        set_proxy_target(&owner1.contract, third_target).await;
+
+        rollback_proxy_target(&owner1.contract).await;
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(SECOND_TARGET)
+        );
+
+        rollback_proxy_target(&owner1.contract).await;
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(third_target)
+        );
+        assert_eq!(proxy_target_history_len(&owner1.contract).await, 5);
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NoPreviousTarget")]
+    async fn when_history_is_empty() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        rollback_proxy_target(&owner1.contract).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        set_proxy_target(&owner1.contract, SECOND_TARGET).await;
+
+        rollback_proxy_target(&owner2.contract).await;
+    }
+}
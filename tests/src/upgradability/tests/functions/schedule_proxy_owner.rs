@@ -0,0 +1,145 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{initialize_proxy_delay, proxy_owner, schedule_proxy_owner, scheduled_proxy_owner},
+    test_helpers::setup,
+    ProxyOwnerScheduled, State,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn schedules_a_new_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        assert_eq!(scheduled_proxy_owner(&owner1.contract).await, None);
+
+        let new_proxy_owner = State::Initialized(owner2.wallet.address().into());
+        let response = schedule_proxy_owner(&owner1.contract, new_proxy_owner.clone(), 100).await;
+
+        let (scheduled_owner, eta) = scheduled_proxy_owner(&owner1.contract).await.unwrap();
+        assert_eq!(scheduled_owner, new_proxy_owner);
+        assert_eq!(
+            proxy_owner(&owner1.contract).await.value,
+            State::Initialized(owner1.wallet.address().into())
+        );
+
+        let log = response
+            .decode_logs_with_type::<ProxyOwnerScheduled>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            ProxyOwnerScheduled {
+                new_proxy_owner,
+                eta,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn schedules_revoked_ownership() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(&owner1.contract, State::Revoked, 100).await;
+
+        let (scheduled_owner, _eta) = scheduled_proxy_owner(&owner1.contract).await.unwrap();
+        assert_eq!(scheduled_owner, State::Revoked);
+    }
+
+    #[tokio::test]
+    async fn replaces_scheduled_owner() {
+        let (deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            100,
+        )
+        .await;
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(deployer.wallet.address().into()),
+            100,
+        )
+        .await;
+
+        let (scheduled_owner, _eta) = scheduled_proxy_owner(&owner1.contract).await.unwrap();
+        assert_eq!(
+            scheduled_owner,
+            State::Initialized(deployer.wallet.address().into())
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(
+            &owner2.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            100,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "CannotUninitialize")]
+    async fn when_scheduling_uninitialized() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(&owner1.contract, State::Uninitialized, 100).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayNotInitialized")]
+    async fn when_delay_not_initialized() {
+        let (_deployer, owner1, owner2) = setup().await;
+
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            100,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayTooShort")]
+    async fn when_delay_less_than_minimum() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            0,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayOverflow")]
+    async fn when_delay_overflows() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+            u64::MAX,
+        )
+        .await;
+    }
+}
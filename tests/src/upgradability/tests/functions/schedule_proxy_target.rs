@@ -0,0 +1,105 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        initialize_proxy_delay, pending_proxy_target, proxy_target, schedule_proxy_target,
+    },
+    test_helpers::{block_timestamp, setup, INITIAL_TARGET, SECOND_TARGET},
+    ProxyTargetScheduled,
+};
+use fuels::types::ContractId;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn schedules_a_new_target() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        assert_eq!(pending_proxy_target(&owner1.contract).await, None);
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        let response = schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+
+        assert_eq!(
+            pending_proxy_target(&owner1.contract).await,
+            Some((SECOND_TARGET, eta))
+        );
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(INITIAL_TARGET)
+        );
+
+        let log = response
+            .decode_logs_with_type::<ProxyTargetScheduled>()
+            .unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(
+            *event,
+            ProxyTargetScheduled {
+                new_target: SECOND_TARGET,
+                eta,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn replaces_scheduled_target() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+        let third_target = ContractId::new([2u8; 32]);
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+        schedule_proxy_target(&owner1.contract, third_target, eta + 100).await;
+
+        assert_eq!(
+            pending_proxy_target(&owner1.contract).await,
+            Some((third_target, eta + 100))
+        );
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner2.wallet).await + 100;
+        schedule_proxy_target(&owner2.contract, SECOND_TARGET, eta).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "InvalidEta")]
+    async fn when_eta_is_in_the_past() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await - 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayNotInitialized")]
+    async fn when_delay_not_initialized() {
+        let (_deployer, owner1, _owner2) = setup().await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 100;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "DelayTooShort")]
+    async fn when_eta_is_before_minimum_delay() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 50).await;
+
+        let eta = block_timestamp(&owner1.wallet).await + 10;
+        schedule_proxy_target(&owner1.contract, SECOND_TARGET, eta).await;
+    }
+}
@@ -1,5 +1,8 @@
 use crate::upgradability::tests::utils::{
-    abi_calls::{proxy_owner, set_proxy_owner},
+    abi_calls::{
+        initialize_proxy_delay, proxy_owner, schedule_proxy_owner, scheduled_proxy_owner,
+        set_proxy_owner,
+    },
     test_helpers::setup,
     State,
 };
@@ -42,6 +45,26 @@ mod success {
 
         assert_eq!(proxy_owner(&owner1.contract).await.value, State::Revoked);
     }
+
+    #[tokio::test]
+    async fn clears_scheduled_proxy_owner() {
+        let (deployer, owner1, owner2) = setup().await;
+        initialize_proxy_delay(&owner1.contract, 100).await;
+        schedule_proxy_owner(
+            &owner1.contract,
+            State::Initialized(deployer.wallet.address().into()),
+            100,
+        )
+        .await;
+
+        set_proxy_owner(
+            &owner1.contract,
+            State::Initialized(owner2.wallet.address().into()),
+        )
+        .await;
+
+        assert_eq!(scheduled_proxy_owner(&owner1.contract).await, None);
+    }
 }
 
 mod reverts {
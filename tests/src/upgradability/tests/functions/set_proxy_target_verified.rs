@@ -0,0 +1,89 @@
+use crate::upgradability::tests::utils::{
+    abi_calls::{
+        approve_implementation_root, proxy_target, revoke_implementation_root,
+        set_proxy_target_verified,
+    },
+    test_helpers::{
+        deploy_implementation, setup, CORRECT_IMPLEMENTATION_PATH, INCORRECT_IMPLEMENTATION_PATH,
+        INITIAL_TARGET,
+    },
+    ProxyTargetSet,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn sets_verified_target() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let (id, root) = deploy_implementation(&owner1.wallet, CORRECT_IMPLEMENTATION_PATH).await;
+
+        approve_implementation_root(&owner1.contract, root).await;
+
+        assert_eq!(
+            proxy_target(&owner1.contract).await.value,
+            Some(INITIAL_TARGET)
+        );
+
+        let response = set_proxy_target_verified(&owner1.contract, id, root).await;
+
+        assert_eq!(proxy_target(&owner1.contract).await.value, Some(id));
+
+        let log = response.decode_logs_with_type::<ProxyTargetSet>().unwrap();
+        let event = log.first().unwrap();
+        assert_eq!(*event, ProxyTargetSet { new_target: id });
+    }
+}
+
+mod reverts {
+
+    use super::*;
+
+    #[tokio::test]
+    #[should_panic(expected = "RootMismatch")]
+    async fn when_target_does_not_match_root() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let (_correct_id, correct_root) =
+            deploy_implementation(&owner1.wallet, CORRECT_IMPLEMENTATION_PATH).await;
+        let (incorrect_id, _incorrect_root) =
+            deploy_implementation(&owner1.wallet, INCORRECT_IMPLEMENTATION_PATH).await;
+
+        approve_implementation_root(&owner1.contract, correct_root).await;
+
+        set_proxy_target_verified(&owner1.contract, incorrect_id, correct_root).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "RootNotApproved")]
+    async fn when_root_not_approved() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let (incorrect_id, incorrect_root) =
+            deploy_implementation(&owner1.wallet, INCORRECT_IMPLEMENTATION_PATH).await;
+
+        set_proxy_target_verified(&owner1.contract, incorrect_id, incorrect_root).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "RootNotApproved")]
+    async fn when_root_revoked() {
+        let (_deployer, owner1, _owner2) = setup().await;
+        let (id, root) = deploy_implementation(&owner1.wallet, CORRECT_IMPLEMENTATION_PATH).await;
+
+        approve_implementation_root(&owner1.contract, root).await;
+        revoke_implementation_root(&owner1.contract, root).await;
+
+        set_proxy_target_verified(&owner1.contract, id, root).await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "NotOwner")]
+    async fn when_called_by_non_owner() {
+        let (_deployer, owner1, owner2) = setup().await;
+        let (id, root) = deploy_implementation(&owner1.wallet, CORRECT_IMPLEMENTATION_PATH).await;
+
+        approve_implementation_root(&owner1.contract, root).await;
+
+        set_proxy_target_verified(&owner2.contract, id, root).await;
+    }
+}
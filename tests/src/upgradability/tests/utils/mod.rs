@@ -1,9 +1,11 @@
+use chrono::Duration;
 use fuels::{
     prelude::{
         abigen, launch_custom_provider_and_get_wallets, Contract, ContractId, LoadConfiguration,
-        StorageConfiguration, TxPolicies, WalletUnlocked, WalletsConfig,
+        StorageConfiguration, TxPolicies, ViewOnlyAccount, WalletUnlocked, WalletsConfig,
     },
     programs::responses::CallResponse,
+    types::{Bits256, Identity},
 };
 
 // Load abi from json
@@ -62,6 +64,258 @@ pub mod abi_calls {
     pub async fn initialize_proxy(contract: &UpgradabilityLib<WalletUnlocked>) -> CallResponse<()> {
         contract.methods().initialize_proxy().call().await.unwrap()
     }
+
+    pub async fn proxy_delay(contract: &UpgradabilityLib<WalletUnlocked>) -> Option<u64> {
+        contract.methods().proxy_delay().call().await.unwrap().value
+    }
+
+    pub async fn initialize_proxy_delay(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        min_delay: u64,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .initialize_proxy_delay(min_delay)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn scheduled_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> Option<(State, u64)> {
+        contract
+            .methods()
+            .scheduled_proxy_owner()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn schedule_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        new_proxy_owner: State,
+        delay: u64,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .schedule_proxy_owner(new_proxy_owner, delay)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn execute_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .execute_proxy_owner()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn cancel_scheduled_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .cancel_scheduled_proxy_owner()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn pending_proxy_target(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> Option<(ContractId, u64)> {
+        contract
+            .methods()
+            .pending_proxy_target()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn schedule_proxy_target(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        new_target: ContractId,
+        eta: u64,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .schedule_proxy_target(new_target, eta)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn execute_proxy_target(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .execute_proxy_target()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn cancel_proxy_target(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .cancel_proxy_target()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn is_implementation_root_approved(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        root: Bits256,
+    ) -> bool {
+        contract
+            .methods()
+            .is_implementation_root_approved(root)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn approve_implementation_root(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        root: Bits256,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .approve_implementation_root(root)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn revoke_implementation_root(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        root: Bits256,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .revoke_implementation_root(root)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn set_proxy_target_verified(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        new_target: ContractId,
+        expected_root: Bits256,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .set_proxy_target_verified(new_target, expected_root)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn proxy_target_history(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        index: u64,
+    ) -> Option<(ContractId, u32)> {
+        contract
+            .methods()
+            .proxy_target_history(index)
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn proxy_target_history_len(contract: &UpgradabilityLib<WalletUnlocked>) -> u64 {
+        contract
+            .methods()
+            .proxy_target_history_len()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn rollback_proxy_target(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .rollback_proxy_target()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn initialize_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        new_proxy_owner: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .initialize_proxy_owner(new_proxy_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn renounce_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .renounce_proxy_owner()
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn pending_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> Option<Identity> {
+        contract
+            .methods()
+            .pending_proxy_owner()
+            .call()
+            .await
+            .unwrap()
+            .value
+    }
+
+    pub async fn propose_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+        new_proxy_owner: Identity,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .propose_proxy_owner(new_proxy_owner)
+            .call()
+            .await
+            .unwrap()
+    }
+
+    pub async fn accept_proxy_owner(
+        contract: &UpgradabilityLib<WalletUnlocked>,
+    ) -> CallResponse<()> {
+        contract
+            .methods()
+            .accept_proxy_owner()
+            .call()
+            .await
+            .unwrap()
+    }
 }
 
 pub mod test_helpers {
@@ -71,8 +325,33 @@ pub mod test_helpers {
 
     pub const INITIAL_TARGET: ContractId = ContractId::zeroed();
     pub const SECOND_TARGET: ContractId = ContractId::new([1u8; 32]);
+    pub const CORRECT_IMPLEMENTATION_PATH: &str =
+        "src/bytecode/test_artifacts/simple_contract/out/release/simple_contract.bin";
+    pub const INCORRECT_IMPLEMENTATION_PATH: &str =
+        "src/bytecode/test_artifacts/complex_contract/out/release/complex_contract.bin";
+    // Offset of the Unix epoch in the TAI64 format returned by `std::block::timestamp()`
+    const TAI64_UNIX_EPOCH: u64 = (1 << 62) + 10;
 
     pub async fn setup() -> (Metadata, Metadata, Metadata) {
+        let storage_configuration = StorageConfiguration::default()
+            .add_slot_overrides_from_file(
+                "src/upgradability/out/release/upgradability_test-storage_slots.json",
+            )
+            .unwrap();
+
+        let (deploy_wallet, owner1, owner2) = deploy(storage_configuration).await;
+
+        initialize_proxy(&deploy_wallet.contract).await;
+
+        (deploy_wallet, owner1, owner2)
+    }
+
+    // Deploys the contract without writing any storage slots
+    pub async fn setup_uninitialized() -> (Metadata, Metadata, Metadata) {
+        deploy(StorageConfiguration::default().with_autoload(false)).await
+    }
+
+    async fn deploy(storage_configuration: StorageConfiguration) -> (Metadata, Metadata, Metadata) {
         let num_wallets = 3;
         let coins_per_wallet = 1;
         let coin_amount = 1_000_000;
@@ -89,12 +368,6 @@ pub mod test_helpers {
         let wallet2 = wallets.pop().unwrap();
         let wallet3 = wallets.pop().unwrap();
 
-        let storage_configuration = StorageConfiguration::default()
-            .add_slot_overrides_from_file(
-                "src/upgradability/out/release/upgradability_test-storage_slots.json",
-            )
-            .unwrap();
-
         let configurables = UpgradabilityLibConfigurables::default()
             .with_INITIAL_TARGET(Some(INITIAL_TARGET))
             .unwrap()
@@ -129,8 +402,44 @@ pub mod test_helpers {
             wallet: wallet3.clone(),
         };
 
-        initialize_proxy(&deploy_wallet.contract).await;
-
         (deploy_wallet, owner1, owner2)
     }
+
+    pub async fn advance_time(wallet: &WalletUnlocked, seconds: i64) {
+        let provider = wallet.try_provider().unwrap();
+        let latest_block_time = provider.latest_block_time().await.unwrap().unwrap();
+        provider
+            .produce_blocks(1, Some(latest_block_time + Duration::seconds(seconds)))
+            .await
+            .unwrap();
+    }
+
+    pub async fn block_timestamp(wallet: &WalletUnlocked) -> u64 {
+        let provider = wallet.try_provider().unwrap();
+        let latest_block_time = provider.latest_block_time().await.unwrap().unwrap();
+        TAI64_UNIX_EPOCH + latest_block_time.timestamp() as u64
+    }
+
+    pub async fn deploy_implementation(
+        wallet: &WalletUnlocked,
+        path: &str,
+    ) -> (ContractId, Bits256) {
+        let contract = Contract::load_from(path, LoadConfiguration::default()).unwrap();
+        let root = Bits256(*contract.code_root());
+        let id = contract
+            .deploy(wallet, TxPolicies::default())
+            .await
+            .unwrap();
+
+        (id.into(), root)
+    }
+
+    pub async fn block_height(wallet: &WalletUnlocked) -> u32 {
+        wallet
+            .try_provider()
+            .unwrap()
+            .latest_block_height()
+            .await
+            .unwrap()
+    }
 }